@@ -0,0 +1,435 @@
+use crate::{
+    Accidental, ChordName, ChordQuality, ChordToneDegree, Key, NoteName, ScaleQuality, SlashChord,
+    SpelledNote,
+};
+use std::fmt::{Display, Formatter};
+
+const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+// functional reading of a chord in a key
+// (ex: in `C` major `Dmin7` -> `ii7`, `E7` -> `V7/vi`, `G7/B` -> `V65`, `Bb` -> `bVII`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RomanNumeral {
+    chord: SlashChord,
+    // scale degree `1..=7` of the root - relative to the `target` for secondary functions
+    degree: usize,
+    // semitones off the major scale's degree for chromatic roots (ex: `-1` for the `bVII` of both
+    // `C` major and `C` minor) - `0` for the key's own degrees
+    alteration: i32,
+    // chromatic root on the major scale's degree where the key has its own (ex: `♮VI` in `C` minor)
+    natural: bool,
+    // diatonic triad being tonicized (ex: the `vi` of `V7/vi`)
+    target: Option<Box<RomanNumeral>>,
+}
+
+impl RomanNumeral {
+    // diatonic chords read as their degree, non-diatonic dominant (`V`) and leading-tone (`vii°`)
+    // chords of a major / minor degree as secondary functions, anything else as a borrowed or
+    // chromatic degree (ex: `Fmin` -> `iv` in `C` major)
+    pub fn analyze(key: &Key, chord: &SlashChord) -> Self {
+        let name = chord.chord();
+        let semitones = semitones_above(&key.tonic().note_name(), &name.get_root());
+
+        if let Some(degree) = diatonic_degree(key, &name) {
+            return Self {
+                chord: *chord,
+                degree,
+                alteration: 0,
+                natural: false,
+                target: None,
+            };
+        }
+
+        if let Some((degree, target)) = secondary_function(key, &name) {
+            return Self {
+                chord: *chord,
+                degree,
+                alteration: 0,
+                natural: false,
+                target: Some(Box::new(target)),
+            };
+        }
+
+        let (degree, alteration) = chromatic_degree(key, &name.get_root(), semitones);
+        Self {
+            chord: *chord,
+            degree,
+            alteration,
+            natural: alteration == 0 && key_degree(key, semitones).is_none(),
+            target: None,
+        }
+    }
+
+    pub fn chord(&self) -> SlashChord {
+        self.chord
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn alteration(&self) -> i32 {
+        self.alteration
+    }
+
+    pub fn target(&self) -> Option<&RomanNumeral> {
+        self.target.as_deref()
+    }
+
+    pub fn is_secondary(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // see `SlashChord::inversion`
+    pub fn inversion(&self) -> Option<usize> {
+        self.chord.inversion()
+    }
+
+    // figured bass of an inverted triad (`6`, `64`) or seventh chord (`65`, `43`, `42`)
+    // (Note: extended / altered chords and foreign basses have no figures)
+    pub fn figures(&self) -> &'static str {
+        let quality = self.chord.chord().get_quality();
+
+        match (is_triad(&quality), is_seventh(&quality), self.inversion()) {
+            (true, _, Some(1)) => "6",
+            (true, _, Some(2)) => "64",
+            (_, true, Some(1)) => "65",
+            (_, true, Some(2)) => "43",
+            (_, true, Some(3)) => "42",
+            _ => "",
+        }
+    }
+}
+
+impl Display for RomanNumeral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let quality = self.chord.chord().get_quality();
+
+        let accidental = match self.natural {
+            true => "♮".to_string(),
+            false => Accidental::from_semitones(self.alteration)
+                .unwrap_or(Accidental::Natural)
+                .to_string(),
+        };
+        let numeral = NUMERALS[(self.degree + 6) % 7];
+        let numeral = match has_minor_third(&quality) {
+            true => numeral.to_lowercase(),
+            false => numeral.to_string(),
+        };
+        write!(f, "{}{}", accidental, numeral)?;
+
+        // inverted seventh chords trade their `7` for the figures (ex: `V7` -> `V65`)
+        let symbol = quality_symbol(&quality);
+        let figures = self.figures();
+        match is_seventh(&quality) && !figures.is_empty() {
+            true => write!(f, "{}{}", symbol.trim_end_matches('7'), figures)?,
+            false => write!(f, "{}{}", symbol, figures)?,
+        }
+
+        if let Some(target) = &self.target {
+            write!(f, "/{}", target)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn semitones_above(from: &NoteName, to: &NoteName) -> usize {
+    (to.pitch_class() + 12 - from.pitch_class()) % 12
+}
+
+// degree number of the key's note `semitones` above the tonic (ex: `9` -> `6` in major)
+fn key_degree(key: &Key, semitones: usize) -> Option<usize> {
+    key.quality()
+        .formula()
+        .into_iter()
+        .find(|(_, degree_semitones)| *degree_semitones == semitones)
+        .map(|(degree, _)| degree)
+}
+
+// root degree of a chord whose tones all sit in the key's scale - minor keys also take their
+// raised sixth / seventh (ex: `G7` and `Bdim7` are `V7` and `vii°7` in `C` minor)
+pub(crate) fn diatonic_degree(key: &Key, name: &ChordName) -> Option<usize> {
+    let forms = match key.quality() {
+        ScaleQuality::Min => vec![
+            ScaleQuality::Min,
+            ScaleQuality::HarmMin,
+            ScaleQuality::MelMin,
+        ],
+        quality => vec![quality],
+    };
+    let tonic = key.tonic().note_name();
+    let root = name.get_root();
+
+    forms.into_iter().find_map(|quality| {
+        let key = Key::new(key.tonic(), quality);
+        let fits = name.get_quality().formula().iter().all(|(_, semitones)| {
+            let note = NoteName::from_pitch_class(root.pitch_class() + semitones);
+            key_degree(&key, semitones_above(&tonic, &note)).is_some()
+        });
+
+        fits.then(|| key_degree(&key, semitones_above(&tonic, &root)))
+            .flatten()
+    })
+}
+
+// `(5 | 7, target)` for a dominant a fifth above, or a leading-tone chord a semitone below, a
+// major / minor diatonic triad other than the tonic (ex: `E7` -> `V7/vi`, `C#dim7` -> `vii°7/ii`)
+fn secondary_function(key: &Key, name: &ChordName) -> Option<(usize, RomanNumeral)> {
+    let quality = name.get_quality();
+    let (degree, semitones) = match (is_dominant(&quality), is_leading_tone(&quality)) {
+        (true, _) => (5, 5),
+        (_, true) => (7, 1),
+        _ => return None,
+    };
+
+    let target_root = semitones_above(&key.tonic().note_name(), &name.get_root()) + semitones;
+    let target_degree = key_degree(key, target_root % 12).filter(|degree| *degree != 1)?;
+    // minor keys tonicize their dominant as the harmonic minor's `V` (ex: `D7` -> `V7/V` in `C` minor)
+    let target = match (key.quality(), target_degree) {
+        (ScaleQuality::Min, 5) => Key::new(key.tonic(), ScaleQuality::HarmMin),
+        _ => *key,
+    }
+    .diatonic_chord(target_degree, false)?;
+    if !matches!(target.get_quality(), ChordQuality::Maj | ChordQuality::Min) {
+        return None;
+    }
+
+    Some((
+        degree,
+        RomanNumeral {
+            chord: SlashChord::from(target),
+            degree: target_degree,
+            alteration: 0,
+            natural: false,
+            target: None,
+        },
+    ))
+}
+
+// letter-based degree + alteration off the major scale of a root outside of the key
+// (ex: `Bb` -> `(7, -1)` in `C` major, `A` -> `(6, 0)` in `C` minor)
+// (Note: keys without accidentals read black-key roots as flats, ex: `bII` rather than `#I`)
+fn chromatic_degree(key: &Key, root: &NoteName, semitones: usize) -> (usize, i32) {
+    let unsigned = key
+        .scale_notes()
+        .iter()
+        .all(|spelled| spelled.accidental() == Accidental::Natural);
+    let spelled = match unsigned {
+        true => SpelledNote::spellings(root)
+            .into_iter()
+            .filter(|spelled| spelled.accidental().semitones() <= 0)
+            .max_by_key(|spelled| spelled.accidental().semitones())
+            .unwrap_or_else(|| SpelledNote::from(*root)),
+        false => key.spell_note(root),
+    };
+
+    let degree = (spelled.letter().index() + 7 - key.tonic().letter().index()) % 7 + 1;
+    // the same reference as `bVII`-style numerals (see `parse_roman_numerals`)
+    let expected = ScaleQuality::Maj
+        .formula()
+        .into_iter()
+        .find(|(number, _)| *number == degree)
+        .map_or(0, |(_, semitones)| semitones);
+    let alteration = (semitones as i32 - expected as i32 + 18).rem_euclid(12) - 6;
+
+    (degree, alteration)
+}
+
+fn third(quality: &ChordQuality) -> Option<usize> {
+    quality
+        .formula()
+        .into_iter()
+        .find(|(degree, _)| *degree == ChordToneDegree::Third)
+        .map(|(_, semitones)| semitones)
+}
+
+fn seventh(quality: &ChordQuality) -> Option<usize> {
+    quality
+        .formula()
+        .into_iter()
+        .find(|(degree, _)| *degree == ChordToneDegree::Seventh)
+        .map(|(_, semitones)| semitones)
+}
+
+fn has_minor_third(quality: &ChordQuality) -> bool {
+    third(quality) == Some(3)
+}
+
+// major triads and dominant sevenths, extended or altered (ex: `G`, `G7`, `G13`, `G7b9`)
+fn is_dominant(quality: &ChordQuality) -> bool {
+    *quality == ChordQuality::Maj || (third(quality) == Some(4) && seventh(quality) == Some(10))
+}
+
+fn is_leading_tone(quality: &ChordQuality) -> bool {
+    matches!(
+        quality,
+        ChordQuality::Dim | ChordQuality::HalfDim | ChordQuality::DimSev
+    )
+}
+
+pub(crate) fn is_triad(quality: &ChordQuality) -> bool {
+    matches!(
+        quality,
+        ChordQuality::Maj | ChordQuality::Min | ChordQuality::Aug | ChordQuality::Dim
+    )
+}
+
+pub(crate) fn is_seventh(quality: &ChordQuality) -> bool {
+    matches!(
+        quality,
+        ChordQuality::MajSev
+            | ChordQuality::MinSev
+            | ChordQuality::Sev
+            | ChordQuality::HalfDim
+            | ChordQuality::DimSev
+            | ChordQuality::MinMajSev
+            | ChordQuality::AugMajSev
+            | ChordQuality::AugSev
+    )
+}
+
+// quality written after the numeral - the numeral's case already carries the third
+// (ex: `ii7` rather than `iimin7`, `I6` stays the first inversion so added sixths read `add6`)
+fn quality_symbol(quality: &ChordQuality) -> String {
+    let symbol = match quality {
+        ChordQuality::Maj | ChordQuality::Min => "",
+        ChordQuality::Aug => "+",
+        ChordQuality::Dim => "°",
+        ChordQuality::Sus2 => "sus2",
+        ChordQuality::Sus4 => "sus4",
+        ChordQuality::MajSev | ChordQuality::MinMajSev => "maj7",
+        ChordQuality::MinSev | ChordQuality::Sev => "7",
+        ChordQuality::SevSus => "7sus4",
+        ChordQuality::HalfDim => "ø7",
+        ChordQuality::DimSev => "°7",
+        ChordQuality::AugMajSev => "+maj7",
+        ChordQuality::AugSev => "+7",
+        ChordQuality::Six | ChordQuality::MinSix => "add6",
+        ChordQuality::SixNine | ChordQuality::MinSixNine => "6/9",
+        ChordQuality::AddNine | ChordQuality::MinAddNine => "add9",
+        ChordQuality::Nine | ChordQuality::MinNine => "9",
+        ChordQuality::MajNine => "maj9",
+        ChordQuality::NineSus => "9sus4",
+        ChordQuality::Eleven | ChordQuality::MinEleven => "11",
+        ChordQuality::Thirteen | ChordQuality::MinThirteen => "13",
+        ChordQuality::MajThirteen => "maj13",
+        ChordQuality::ThirteenSus => "13sus4",
+        ChordQuality::Altered(base, tensions) => {
+            return format!("{}{}", quality_symbol(&base.quality()), tensions)
+        }
+    };
+
+    symbol.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(quality: ScaleQuality) -> Key {
+        Key::new(SpelledNote::from(NoteName::C), quality)
+    }
+
+    fn analyze(key: &Key, symbol: &str) -> RomanNumeral {
+        let chord = SlashChord::try_from(symbol).expect("should parse the chord symbol");
+        RomanNumeral::analyze(key, &chord)
+    }
+
+    fn in_major(symbol: &str) -> String {
+        analyze(&key(ScaleQuality::Maj), symbol).to_string()
+    }
+
+    fn in_minor(symbol: &str) -> String {
+        analyze(&key(ScaleQuality::Min), symbol).to_string()
+    }
+
+    #[test]
+    fn diatonic_chords_read_the_same_in_either_mode() {
+        for symbol in [
+            "Dm7", "G7", "Cmaj7", "G7/B", "G/D", "F7/Eb", "D7/F#", "Bdim7", "Fm",
+        ] {
+            assert_eq!(in_major(symbol), in_minor(symbol), "{symbol}");
+        }
+
+        assert_eq!(in_major("Dm7"), "ii7");
+        assert_eq!(in_major("G7"), "V7");
+        assert_eq!(in_major("Cmaj7"), "Imaj7");
+        assert_eq!(in_major("G7/B"), "V65");
+        assert_eq!(in_major("G/D"), "V64");
+        assert_eq!(in_major("F7/Eb"), "IV42");
+        assert_eq!(in_major("D7/F#"), "V65/V");
+        assert_eq!(in_major("Bdim7"), "vii°7");
+        assert_eq!(in_major("Fm"), "iv");
+    }
+
+    #[test]
+    fn flat_degrees_are_borrowed_in_major_and_diatonic_in_minor() {
+        assert_eq!(in_major("Bb"), "bVII");
+        assert_eq!(in_minor("Bb"), "VII");
+        assert_eq!(in_major("Ab"), "bVI");
+        assert_eq!(in_minor("Ab"), "VI");
+        // the Neapolitan is chromatic in both
+        assert_eq!(in_major("Db"), "bII");
+        assert_eq!(in_minor("Db"), "bII");
+    }
+
+    #[test]
+    fn secondary_dominants_name_their_target() {
+        let numeral = analyze(&key(ScaleQuality::Maj), "E7");
+
+        assert_eq!(numeral.to_string(), "V7/vi");
+        assert!(numeral.is_secondary());
+        assert_eq!(numeral.degree(), 5);
+        let target = numeral.target().expect("should tonicize a degree");
+        assert_eq!(target.degree(), 6);
+        assert_eq!(target.chord().to_string(), "Amin");
+
+        // leading-tone chords tonicize the degree a semitone above
+        assert_eq!(
+            analyze(&key(ScaleQuality::Maj), "C#dim7").to_string(),
+            "vii°7/ii"
+        );
+    }
+
+    #[test]
+    fn borrowed_chords_carry_their_alteration() {
+        let numeral = analyze(&key(ScaleQuality::Maj), "Bb");
+
+        assert_eq!(numeral.degree(), 7);
+        assert_eq!(numeral.alteration(), -1);
+        assert!(!numeral.is_secondary());
+    }
+
+    #[test]
+    fn minor_keys_mark_major_scale_degrees() {
+        assert_eq!(in_minor("A"), "♮VI");
+        assert_eq!(in_minor("B"), "♮VII");
+        assert_eq!(in_minor("Em"), "♮iii");
+        // degrees off both scales keep their flat
+        assert_eq!(in_minor("Gb"), "bV");
+    }
+
+    #[test]
+    fn inversions_take_figures() {
+        // (chord, figures)
+        let inversions = [
+            ("C", ""),
+            ("C/E", "6"),
+            ("C/G", "64"),
+            ("G7", ""),
+            ("G7/B", "65"),
+            ("G7/D", "43"),
+            ("G7/F", "42"),
+            ("G9/B", ""),
+        ];
+
+        for (symbol, figures) in inversions {
+            assert_eq!(
+                analyze(&key(ScaleQuality::Maj), symbol).figures(),
+                figures,
+                "{symbol}"
+            );
+        }
+    }
+}
@@ -1,20 +1,61 @@
 use once_cell::sync::Lazy;
 
-use crate::{generate_music_data, ChordName, ChordSpelling, NoteName, NoteOct};
+use crate::chords::ChordNameError;
+use crate::data_sets::{generate_complete_chord_spellings, generate_music_data_for};
+use crate::scales_generator::generate_scale_spellings;
+use crate::{
+    ChordDictionary, ChordName, ChordSpelling, DictionaryError, NoteName, NoteOct, ScaleDictionary,
+    ScaleName, ScaleSpelling, SlashChord, SpelledNote, SpellingForm, Tuning, CONCERT_PITCH,
+};
 use std::collections::HashMap;
 
+#[derive(Debug, thiserror::Error)]
+pub enum MusicTheoryBauxError {
+    #[error("ERROR: invalid reference pitch `{0}` (Note: `A4` reference pitch must be a positive, finite frequency ex: `440.0`, `442.0`, `432.0`)")]
+    InvalidReferencePitch(f64),
+    #[error(transparent)]
+    Dictionary(#[from] DictionaryError),
+}
+
 pub struct MusicTheoryBaux {
+    reference_pitch: f64,
+    tuning: Tuning,
     note_freqs: HashMap<NoteOct, f64>,
-    freq_notes: HashMap<&'static str, NoteOct>,
+    freq_notes: HashMap<String, NoteOct>,
     note_freq_collections: HashMap<NoteName, Vec<f64>>,
     note_weights: HashMap<NoteOct, usize>,
     weight_notes: HashMap<usize, NoteOct>,
     chord_spellings: HashMap<ChordName, ChordSpelling>,
+    complete_chord_spellings: HashMap<ChordName, ChordSpelling>,
+    chord_aliases: HashMap<String, SlashChord>,
+    scale_spellings: HashMap<ScaleName, ScaleSpelling>,
     enharmonics: HashMap<NoteName, NoteName>,
 }
 
 impl MusicTheoryBaux {
     pub fn new() -> Self {
+        Self::tuned(CONCERT_PITCH, Tuning::EqualTemperament)
+    }
+
+    // reference pitch, tuning and dictionaries set up piece by piece (see `MusicTheoryBauxBuilder`)
+    pub fn builder() -> MusicTheoryBauxBuilder {
+        MusicTheoryBauxBuilder::default()
+    }
+
+    // frequencies tuned to `A4 = reference_pitch` (ex: `442.0` for many European orchestras)
+    pub fn with_reference_pitch(reference_pitch: f64) -> Result<Self, MusicTheoryBauxError> {
+        Self::builder().reference_pitch(reference_pitch).build()
+    }
+
+    // frequencies from `tuning` (ex: `Tuning::Werckmeister`) around `A4 = reference_pitch`
+    pub fn with_tuning(reference_pitch: f64, tuning: Tuning) -> Result<Self, MusicTheoryBauxError> {
+        Self::builder()
+            .reference_pitch(reference_pitch)
+            .tuning(tuning)
+            .build()
+    }
+
+    fn tuned(reference_pitch: f64, tuning: Tuning) -> Self {
         let (
             note_freqs,
             freq_notes,
@@ -24,25 +65,63 @@ impl MusicTheoryBaux {
             chord_spellings,
             enharmonics,
             _scale_relatives,
-        ) = generate_music_data();
+        ) = generate_music_data_for(reference_pitch, &tuning);
+        let complete_chord_spellings = generate_complete_chord_spellings(&chord_spellings);
+        let scale_spellings = generate_scale_spellings();
 
         Self {
+            reference_pitch,
+            tuning,
             note_freqs,
             freq_notes,
             note_freq_collections,
             note_weights,
             weight_notes,
             chord_spellings,
+            complete_chord_spellings,
+            chord_aliases: HashMap::new(),
+            scale_spellings,
             enharmonics,
         }
     }
 
+    // dictionary entries replace built-in spellings of the same chord / scale
+    fn add_chord_dictionary(&mut self, dictionary: ChordDictionary) {
+        for chord_spelling in dictionary.spellings() {
+            let spellings = match chord_spelling.form() {
+                SpellingForm::Shell => &mut self.chord_spellings,
+                SpellingForm::Complete => &mut self.complete_chord_spellings,
+            };
+            spellings.insert(*chord_spelling.name(), chord_spelling.clone());
+        }
+
+        self.chord_aliases
+            .extend(dictionary.aliases().iter().cloned());
+    }
+
+    fn add_scale_dictionary(&mut self, dictionary: ScaleDictionary) {
+        for scale_spelling in dictionary.scales() {
+            self.scale_spellings
+                .insert(*scale_spelling.name(), scale_spelling.clone());
+        }
+    }
+
     // === DATA COLLECTION(S) ACCESS === //
+    pub fn reference_pitch(&self) -> f64 {
+        self.reference_pitch
+    }
+
+    pub fn tuning(&self) -> &Tuning {
+        &self.tuning
+    }
+
     pub fn note_freqs(&self) -> &HashMap<NoteOct, f64> {
         &self.note_freqs
     }
 
-    pub fn freq_notes(&self) -> &HashMap<&'static str, NoteOct> {
+    // (Note: keyed by the frequency's `to_string()` - keys used to be `&'static str` when the
+    // frequencies were fixed at 440 Hz)
+    pub fn freq_notes(&self) -> &HashMap<String, NoteOct> {
         &self.freq_notes
     }
 
@@ -50,6 +129,7 @@ impl MusicTheoryBaux {
         &self.note_freq_collections
     }
 
+    // see `get_note_weight`
     pub fn note_weights(&self) -> &HashMap<NoteOct, usize> {
         &self.note_weights
     }
@@ -62,6 +142,18 @@ impl MusicTheoryBaux {
         &self.chord_spellings
     }
 
+    pub fn complete_chord_spellings(&self) -> &HashMap<ChordName, ChordSpelling> {
+        &self.complete_chord_spellings
+    }
+
+    pub fn chord_aliases(&self) -> &HashMap<String, SlashChord> {
+        &self.chord_aliases
+    }
+
+    pub fn scale_spellings(&self) -> &HashMap<ScaleName, ScaleSpelling> {
+        &self.scale_spellings
+    }
+
     pub fn enharmonics(&self) -> &HashMap<NoteName, NoteName> {
         &self.enharmonics
     }
@@ -79,15 +171,50 @@ impl MusicTheoryBaux {
         // tolerance for 'close' check of our f64 frequency values (with `0.000000001`)
         const EPSILON: f64 = 1e-9;
 
-        self.freq_notes
-            .iter()
-            .find(|(&k, _)| {
-                let k_freq: f64 = k.parse().unwrap_or(f64::NAN);
-                (k_freq - freq).abs() < EPSILON
+        let (nearest, _) = self.get_nearest_note(freq)?;
+
+        self.note_freqs
+            .get_key_value(&nearest)
+            .filter(|(_, &note_freq)| (note_freq - freq).abs() < EPSILON)
+            .map(|(note, _)| note)
+    }
+
+    // closest note to any `freq` + its signed deviation in cents (ex: `445.0` -> `A4`, `+19.56`)
+    // (Note: `None` for frequencies outside of the `C-2` to `B9` range)
+    pub fn get_nearest_note(&self, freq: f64) -> Option<(NoteOct, f64)> {
+        if !freq.is_finite() || freq <= 0.0 {
+            return None;
+        }
+
+        // equal tempered estimate of the note's `weight` - neighbours are checked against this
+        // baux's own frequencies, since other tunings shift notes away from equal temperament
+        let a4_weight = *self.note_weights.get(&NoteOct::A(4))? as f64;
+        let estimate = a4_weight + 12.0 * (freq / self.reference_pitch).log2();
+        let highest = (self.weight_notes.len() - 1) as f64;
+        if !(-0.5..=highest + 0.5).contains(&estimate) {
+            return None;
+        }
+
+        let estimate = estimate.round() as i64;
+        (estimate - 1..=estimate + 1)
+            .filter_map(|weight| usize::try_from(weight).ok())
+            .filter_map(|weight| self.weight_notes.get(&weight))
+            .filter_map(|note| {
+                let note_freq = self.note_freqs.get(note)?;
+                Some((*note, 1200.0 * (freq / note_freq).log2()))
             })
-            .map(|(_, v)| v)
+            .min_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
     }
 
+    // frequency of `note` shifted by `cents` (ex: `A4` + `-15.0` cents)
+    pub fn get_freq_with_cents(&self, note: &NoteOct, cents: f64) -> Option<f64> {
+        self.note_freqs
+            .get(note)
+            .map(|freq| freq * 2f64.powf(cents / 1200.0))
+    }
+
+    // semitones above the lowest supported note (`C-2 = 0`, `C1 = 36`, `C4 = 72`)
+    // (Note: weights used to start from `C1 = 0` - subtract `36` for the old values)
     pub fn get_note_weight(&self, note: &NoteOct) -> Option<&usize> {
         self.note_weights.get(note)
     }
@@ -100,6 +227,43 @@ impl MusicTheoryBaux {
         self.chord_spellings.get(chord)
     }
 
+    // shell (`get_chord_spelling`) or complete spelling of `chord` (ex: `Cmaj7` -> `C`, `E`, `G`, `B`)
+    pub fn get_chord_spelling_form(
+        &self,
+        chord: &ChordName,
+        form: SpellingForm,
+    ) -> Option<&ChordSpelling> {
+        match form {
+            SpellingForm::Shell => self.chord_spellings.get(chord),
+            SpellingForm::Complete => self.complete_chord_spellings.get(chord),
+        }
+    }
+
+    // dictionary named chord (ex: `So What` -> `Emin11`)
+    pub fn get_chord_alias(&self, alias: &str) -> Option<&SlashChord> {
+        self.chord_aliases.get(alias)
+    }
+
+    // dictionary named chord or chord symbol (ex: `So What` -> `Emin11`, `Cmaj7/E` as it is)
+    // (Note: `SlashChord::try_from` and `from_input` only read chord symbols - see `chord_dictionary`)
+    pub fn parse_chord(&self, symbol: &str) -> Result<SlashChord, ChordNameError> {
+        match self.get_chord_alias(symbol.trim()) {
+            Some(chord) => Ok(*chord),
+            None => SlashChord::try_from(symbol),
+        }
+    }
+
+    pub fn get_scale_spelling(&self, scale: &ScaleName) -> Option<&ScaleSpelling> {
+        self.scale_spellings.get(scale)
+    }
+
+    // letter-based scale notes, tonic first (ex: `C Maj` -> `C`, `D`, `E`, `F`, `G`, `A`, `B`)
+    pub fn get_scale_notes(&self, scale: &ScaleName) -> Option<&Vec<SpelledNote>> {
+        self.scale_spellings
+            .get(scale)
+            .map(|scale_spelling| scale_spelling.spelled_notes())
+    }
+
     pub fn get_enharmonic(&self, note: &NoteName) -> Option<&NoteName> {
         self.enharmonics.get(note)
     }
@@ -111,5 +275,128 @@ impl Default for MusicTheoryBaux {
     }
 }
 
+pub struct MusicTheoryBauxBuilder {
+    reference_pitch: f64,
+    tuning: Tuning,
+    chord_dictionaries: Vec<ChordDictionary>,
+    scale_dictionaries: Vec<ScaleDictionary>,
+}
+
+impl MusicTheoryBauxBuilder {
+    pub fn reference_pitch(mut self, reference_pitch: f64) -> Self {
+        self.reference_pitch = reference_pitch;
+        self
+    }
+
+    pub fn tuning(mut self, tuning: Tuning) -> Self {
+        self.tuning = tuning;
+        self
+    }
+
+    // applied in order - later dictionaries win for the same chord (ex: house spellings)
+    // (Note: entries only reach the built baux, not the global `music_theory_baux` read by
+    // `ChordName::spell`, `SlashChord::spelling`, `ChordVoicing::voice_lead`, `identify_chord` and
+    // `from_input` - look spellings up with `get_chord_spelling_form` and names with `parse_chord`)
+    pub fn chord_dictionary(mut self, dictionary: ChordDictionary) -> Self {
+        self.chord_dictionaries.push(dictionary);
+        self
+    }
+
+    // (Note: like `chord_dictionary`, entries only reach the built baux - see `get_scale_spelling`)
+    pub fn scale_dictionary(mut self, dictionary: ScaleDictionary) -> Self {
+        self.scale_dictionaries.push(dictionary);
+        self
+    }
+
+    pub fn build(self) -> Result<MusicTheoryBaux, MusicTheoryBauxError> {
+        if !self.reference_pitch.is_finite() || self.reference_pitch <= 0.0 {
+            return Err(MusicTheoryBauxError::InvalidReferencePitch(
+                self.reference_pitch,
+            ));
+        }
+
+        let mut baux = MusicTheoryBaux::tuned(self.reference_pitch, self.tuning);
+        for dictionary in self.chord_dictionaries {
+            baux.add_chord_dictionary(dictionary);
+        }
+        for dictionary in self.scale_dictionaries {
+            baux.add_scale_dictionary(dictionary);
+        }
+
+        Ok(baux)
+    }
+}
+
+impl Default for MusicTheoryBauxBuilder {
+    fn default() -> Self {
+        Self {
+            reference_pitch: CONCERT_PITCH,
+            tuning: Tuning::EqualTemperament,
+            chord_dictionaries: Vec::new(),
+            scale_dictionaries: Vec::new(),
+        }
+    }
+}
+
 #[allow(non_upper_case_globals)]
 pub static music_theory_baux: Lazy<MusicTheoryBaux> = Lazy::new(MusicTheoryBaux::new);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_notes_carry_their_cents_deviation() {
+        let baux = MusicTheoryBaux::new();
+
+        let (note, cents) = baux.get_nearest_note(445.0).unwrap();
+        assert_eq!(note, NoteOct::A(4));
+        assert!((cents - 19.56).abs() < 0.01, "`{cents}`");
+
+        let (note, cents) = baux.get_nearest_note(261.0).unwrap();
+        assert_eq!(note, NoteOct::C(4));
+        assert!(cents < 0.0);
+
+        for freq in [0.0, -440.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(baux.get_nearest_note(freq), None, "`{freq}`");
+        }
+    }
+
+    #[test]
+    fn nearest_notes_stop_half_a_semitone_past_the_range() {
+        let baux = MusicTheoryBaux::new();
+        let lowest = *baux.get_freq(&NoteOct::C(-2)).unwrap();
+        let highest = *baux.get_freq(&NoteOct::B(9)).unwrap();
+
+        assert_eq!(
+            baux.get_nearest_note(lowest).unwrap(),
+            (NoteOct::C(-2), 0.0)
+        );
+        assert_eq!(baux.get_nearest_note(highest).unwrap().0, NoteOct::B(9));
+
+        let (note, cents) = baux
+            .get_nearest_note(baux.get_freq_with_cents(&NoteOct::B(9), 40.0).unwrap())
+            .unwrap();
+        assert_eq!(note, NoteOct::B(9));
+        assert!((cents - 40.0).abs() < 1e-6);
+
+        let below = baux.get_freq_with_cents(&NoteOct::C(-2), -60.0).unwrap();
+        let above = baux.get_freq_with_cents(&NoteOct::B(9), 60.0).unwrap();
+        assert_eq!(baux.get_nearest_note(below), None);
+        assert_eq!(baux.get_nearest_note(above), None);
+    }
+
+    #[test]
+    fn cents_offsets_follow_the_reference_pitch() {
+        let baux = MusicTheoryBaux::with_reference_pitch(432.0).unwrap();
+
+        assert_eq!(baux.get_freq_with_cents(&NoteOct::A(4), 0.0), Some(432.0));
+        let octave_up = baux.get_freq_with_cents(&NoteOct::A(4), 1200.0).unwrap();
+        assert!((octave_up - 864.0).abs() < 1e-9);
+        assert_eq!(baux.get_freq_with_cents(&NoteOct::C(10), 0.0), None);
+
+        let (note, cents) = baux.get_nearest_note(440.0).unwrap();
+        assert_eq!(note, NoteOct::A(4));
+        assert!((cents - 31.77).abs() < 0.01, "`{cents}`");
+    }
+}
@@ -0,0 +1,447 @@
+use crate::{
+    Accidental, ChordName, ChordQuality, Letter, SlashChord, SpelledChord, SpelledNote,
+    SpellingForm, Tensions,
+};
+
+// chord symbol conventions for `format` (ex: `Cmaj7` as jazz `CΔ7`, LilyPond `c:maj7`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ChordSymbolStyle {
+    // this crate's own `Display` (ex: `Cmaj7`, `Cmin7`, `C°7`)
+    #[default]
+    Standard,
+    // `CΔ7`, `C-7`, `Cø7`, `C7♭9`
+    Jazz,
+    // classical / pop lead sheets - `Cmaj7`, `Cm7`, `Cm7b5`, `C7b9`
+    Pop,
+    // Berklee chord symbols - `CMaj7`, `C-7`, `C-7(♭5)`, `C7(♭9, ♯11)`
+    Berklee,
+    // extensions raised - `Cᵐᵃʲ⁷`, `Cm⁷`, `C⁷♭⁹`
+    Superscript,
+    // 7-bit safe - `Cmaj7`, `Cm7`, `Cm7b5`, `C7b9`
+    Ascii,
+    // LilyPond `\chordmode` (ex: `c:maj7`, `bes:m7.5-`, `c:7.9-`)
+    LilyPond,
+    // MusicXML `<kind>` values (ex: `C major-seventh`, `C dominant`)
+    // (Note: alterations belong in MusicXML `<degree>` elements - see `Tensions::alterations`)
+    MusicXml,
+}
+
+impl ChordQuality {
+    // quality symbol alone in `style` (ex: `MajSev` -> `Δ7` for `ChordSymbolStyle::Jazz`)
+    pub fn format(&self, style: ChordSymbolStyle) -> String {
+        match (style, self) {
+            (ChordSymbolStyle::Standard, _) => self.to_string(),
+            (ChordSymbolStyle::Superscript, _) => {
+                let symbol = self.format(ChordSymbolStyle::Pop);
+                // the triad symbol stays on the line, everything after it is raised
+                let triad_len = match symbol.starts_with("maj") {
+                    true => 0,
+                    false => ["dim", "aug", "m"]
+                        .iter()
+                        .find(|triad| symbol.starts_with(**triad))
+                        .map_or(0, |triad| triad.len()),
+                };
+
+                format!(
+                    "{}{}",
+                    &symbol[..triad_len],
+                    superscript(&symbol[triad_len..])
+                )
+            }
+            (_, ChordQuality::Altered(base, tensions)) => {
+                let base = base.quality().format(style);
+
+                match style {
+                    ChordSymbolStyle::MusicXml => base,
+                    ChordSymbolStyle::LilyPond => format!("{}.{}", base, tensions.format(style)),
+                    ChordSymbolStyle::Berklee if *tensions != Tensions::ALT => {
+                        format!("{}({})", base, tensions.format(style))
+                    }
+                    _ => format!("{}{}", base, tensions.format(style)),
+                }
+            }
+            (style, quality) => {
+                let [jazz, pop, berklee, ascii, lilypond, musicxml] = symbols(quality);
+
+                match style {
+                    ChordSymbolStyle::Jazz => jazz,
+                    ChordSymbolStyle::Berklee => berklee,
+                    ChordSymbolStyle::Ascii => ascii,
+                    ChordSymbolStyle::LilyPond => lilypond,
+                    ChordSymbolStyle::MusicXml => musicxml,
+                    _ => pop,
+                }
+                .to_string()
+            }
+        }
+    }
+}
+
+impl Tensions {
+    // tensions alone in `style` (ex: `b9` + `#11` -> `♭9♯11` jazz, `♭9, ♯11` Berklee, `9-.11+` LilyPond)
+    pub fn format(&self, style: ChordSymbolStyle) -> String {
+        match style {
+            ChordSymbolStyle::Standard | ChordSymbolStyle::Pop | ChordSymbolStyle::Ascii => {
+                self.to_string()
+            }
+            ChordSymbolStyle::Superscript => superscript(&self.to_string()),
+            ChordSymbolStyle::Jazz | ChordSymbolStyle::Berklee if *self == Tensions::ALT => {
+                self.to_string()
+            }
+            ChordSymbolStyle::Jazz | ChordSymbolStyle::Berklee => {
+                let separator = match style {
+                    ChordSymbolStyle::Berklee => ", ",
+                    _ => "",
+                };
+
+                alterations(self)
+                    .iter()
+                    .map(|(semitones, degree)| {
+                        let accidental = if *semitones < 0 { "♭" } else { "♯" };
+                        format!("{accidental}{degree}")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(separator)
+            }
+            ChordSymbolStyle::LilyPond => alterations(self)
+                .iter()
+                .map(|(semitones, degree)| {
+                    let accidental = if *semitones < 0 { "-" } else { "+" };
+                    format!("{degree}{accidental}")
+                })
+                .collect::<Vec<_>>()
+                .join("."),
+            // MusicXML `<degree-value>` + `<degree-alter>` pairs (ex: `9 -1, 11 1`)
+            ChordSymbolStyle::MusicXml => alterations(self)
+                .iter()
+                .map(|(semitones, degree)| format!("{degree} {semitones}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+}
+
+impl ChordName {
+    // chord symbol with a letter-based root in `style` (ex: `D#/Ebmin7` -> `E♭-7` jazz, `es:m7` LilyPond)
+    // (Note: the root letter is the one with the fewest accidentals - see `SpelledChord::format`
+    // to keep a written root, ex: `Gb7` rather than `F#7`)
+    pub fn format(&self, style: ChordSymbolStyle) -> String {
+        format_chord(&self.spelled_root(None), &self.get_quality(), style)
+    }
+}
+
+impl SlashChord {
+    // slash chord symbol in `style` (ex: `Cmaj/E` -> `C/E` jazz, `c/e` LilyPond)
+    pub fn format(&self, style: ChordSymbolStyle) -> String {
+        let chord = self.chord().format(style);
+        if self.is_root_position() {
+            return chord;
+        }
+
+        // same letter as the bass tone of the chord's own spelling (ex: `Eb` rather than `D#`)
+        let bass = self
+            .spelling(SpellingForm::Shell)
+            .and_then(|chord_spelling| chord_spelling.get_spelled_note(&self.bass()).copied())
+            .unwrap_or_else(|| SpelledNote::from(self.bass()));
+
+        format!("{}/{}", chord, format_note(&bass, style))
+    }
+}
+
+impl SpelledChord {
+    // chord symbol in `style` with the written letters (ex: `Bbm7b5` -> `B♭ø7` jazz, `bes:m7.5-`
+    // LilyPond rather than `A♯ø7`, `ais:m7.5-`)
+    pub fn format(&self, style: ChordSymbolStyle) -> String {
+        let chord = format_chord(&self.root(None), &self.chord().chord().get_quality(), style);
+        if self.chord().is_root_position() {
+            return chord;
+        }
+
+        format!("{}/{}", chord, format_note(&self.bass(None), style))
+    }
+}
+
+// `root` + `quality` symbol in `style` (ex: `Eb` + `MinSev` -> `es:m7` LilyPond)
+fn format_chord(root: &SpelledNote, quality: &ChordQuality, style: ChordSymbolStyle) -> String {
+    let quality = quality.format(style);
+
+    match style {
+        ChordSymbolStyle::LilyPond if quality.is_empty() => format_note(root, style),
+        ChordSymbolStyle::LilyPond => format!("{}:{}", format_note(root, style), quality),
+        ChordSymbolStyle::MusicXml => format!("{} {}", format_note(root, style), quality),
+        _ => format!("{}{}", format_note(root, style), quality),
+    }
+}
+
+// `[jazz, pop, berklee, ascii, lilypond, musicxml]` symbols for an unaltered quality
+fn symbols(quality: &ChordQuality) -> [&'static str; 6] {
+    match quality {
+        ChordQuality::Maj => ["", "", "", "", "", "major"],
+        ChordQuality::Min => ["-", "m", "-", "m", "m", "minor"],
+        ChordQuality::Aug => ["+", "aug", "+", "aug", "aug", "augmented"],
+        ChordQuality::Dim => ["°", "dim", "o", "dim", "dim", "diminished"],
+        ChordQuality::Sus2 => ["sus2", "sus2", "sus2", "sus2", "sus2", "suspended-second"],
+        ChordQuality::Sus4 => ["sus4", "sus4", "sus4", "sus4", "sus4", "suspended-fourth"],
+        ChordQuality::MajSev => ["Δ7", "maj7", "Maj7", "maj7", "maj7", "major-seventh"],
+        ChordQuality::MinSev => ["-7", "m7", "-7", "m7", "m7", "minor-seventh"],
+        ChordQuality::Sev => ["7", "7", "7", "7", "7", "dominant"],
+        ChordQuality::SevSus => [
+            "7sus4",
+            "7sus4",
+            "7sus4",
+            "7sus4",
+            "7sus4",
+            "suspended-fourth",
+        ],
+        ChordQuality::HalfDim => ["ø7", "m7b5", "-7(♭5)", "m7b5", "m7.5-", "half-diminished"],
+        ChordQuality::DimSev => ["°7", "dim7", "o7", "dim7", "dim7", "diminished-seventh"],
+        ChordQuality::MinMajSev => ["-Δ7", "m(maj7)", "-(Maj7)", "mmaj7", "m7+", "major-minor"],
+        ChordQuality::AugMajSev => [
+            "+Δ7",
+            "maj7#5",
+            "Maj7(♯5)",
+            "maj7#5",
+            "maj7.5+",
+            "major-seventh",
+        ],
+        ChordQuality::AugSev => ["+7", "7#5", "+7", "7#5", "aug7", "augmented-seventh"],
+        ChordQuality::Six => ["6", "6", "6", "6", "6", "major-sixth"],
+        ChordQuality::MinSix => ["-6", "m6", "-6", "m6", "m6", "minor-sixth"],
+        ChordQuality::SixNine => ["6/9", "6/9", "6/9", "6/9", "6.9", "major-sixth"],
+        ChordQuality::MinSixNine => ["-6/9", "m6/9", "-6/9", "m6/9", "m6.9", "minor-sixth"],
+        ChordQuality::AddNine => ["add9", "add9", "(add9)", "add9", "5.9", "major"],
+        ChordQuality::MinAddNine => ["-(add9)", "m(add9)", "-(add9)", "madd9", "m5.9", "minor"],
+        ChordQuality::Nine => ["9", "9", "9", "9", "9", "dominant-ninth"],
+        ChordQuality::MajNine => ["Δ9", "maj9", "Maj9", "maj9", "maj9", "major-ninth"],
+        ChordQuality::MinNine => ["-9", "m9", "-9", "m9", "m9", "minor-ninth"],
+        ChordQuality::NineSus => [
+            "9sus4",
+            "9sus4",
+            "9sus4",
+            "9sus4",
+            "9sus4",
+            "suspended-fourth",
+        ],
+        ChordQuality::Eleven => ["11", "11", "11", "11", "11", "dominant-11th"],
+        ChordQuality::MinEleven => ["-11", "m11", "-11", "m11", "m11", "minor-11th"],
+        ChordQuality::Thirteen => ["13", "13", "13", "13", "13", "dominant-13th"],
+        ChordQuality::MajThirteen => ["Δ13", "maj13", "Maj13", "maj13", "maj13", "major-13th"],
+        ChordQuality::MinThirteen => ["-13", "m13", "-13", "m13", "m13", "minor-13th"],
+        ChordQuality::ThirteenSus => [
+            "13sus4",
+            "13sus4",
+            "13sus4",
+            "13sus4",
+            "13sus4",
+            "suspended-fourth",
+        ],
+        // Note: altered qualities are built from their base (see `ChordQuality::format`)
+        ChordQuality::Altered(..) => ["", "", "", "", "", ""],
+    }
+}
+
+// `(accidental semitones, degree)` for each tension, lowest to highest (ex: `b9` -> `(-1, 9)`)
+fn alterations(tensions: &Tensions) -> Vec<(i32, usize)> {
+    [(-1, 5), (1, 5), (-1, 9), (1, 9), (1, 11), (-1, 13)]
+        .into_iter()
+        .filter(|(semitones, degree)| {
+            Tensions::from_alteration(*semitones, *degree)
+                .is_some_and(|tension| tensions.contains(tension))
+        })
+        .collect()
+}
+
+// note name in `style` (ex: `Eb` -> `E♭` jazz, `es` LilyPond)
+fn format_note(note: &SpelledNote, style: ChordSymbolStyle) -> String {
+    match style {
+        ChordSymbolStyle::Jazz | ChordSymbolStyle::Berklee | ChordSymbolStyle::Superscript => {
+            let accidental = match note.accidental() {
+                Accidental::DoubleFlat => "𝄫",
+                Accidental::Flat => "♭",
+                Accidental::Natural => "",
+                Accidental::Sharp => "♯",
+                Accidental::DoubleSharp => "𝄪",
+            };
+            format!("{}{}", note.letter(), accidental)
+        }
+        ChordSymbolStyle::LilyPond => {
+            let letter = note.letter().to_string().to_lowercase();
+            let accidental = match (note.letter(), note.accidental()) {
+                // `es` / `as` rather than `ees` / `aes`
+                (Letter::E | Letter::A, Accidental::DoubleFlat) => "ses",
+                (Letter::E | Letter::A, Accidental::Flat) => "s",
+                (_, Accidental::DoubleFlat) => "eses",
+                (_, Accidental::Flat) => "es",
+                (_, Accidental::Natural) => "",
+                (_, Accidental::Sharp) => "is",
+                (_, Accidental::DoubleSharp) => "isis",
+            };
+            format!("{}{}", letter, accidental)
+        }
+        _ => note.to_string(),
+    }
+}
+
+// raised digits and symbols for superscript extensions (unmapped characters stay as they are)
+fn superscript(symbol: &str) -> String {
+    symbol
+        .chars()
+        .map(|c| match c {
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            '+' => '⁺',
+            '(' => '⁽',
+            ')' => '⁾',
+            'b' => '♭',
+            '#' => '♯',
+            'a' => 'ᵃ',
+            'd' => 'ᵈ',
+            'i' => 'ⁱ',
+            'j' => 'ʲ',
+            'l' => 'ˡ',
+            'm' => 'ᵐ',
+            's' => 'ˢ',
+            't' => 'ᵗ',
+            'u' => 'ᵘ',
+            '/' => 'ᐟ',
+            other => other,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn styled(symbol: &str, style: ChordSymbolStyle) -> String {
+        let chord_name = ChordName::try_from(symbol).expect("should parse the chord symbol");
+
+        chord_name.format(style)
+    }
+
+    #[test]
+    fn jazz_style_uses_symbols() {
+        let jazz = |symbol| styled(symbol, ChordSymbolStyle::Jazz);
+
+        assert_eq!(jazz("C"), "C");
+        assert_eq!(jazz("Cmaj7"), "CΔ7");
+        assert_eq!(jazz("Ebm7"), "E♭-7");
+        assert_eq!(jazz("Bm7b5"), "Bø7");
+        assert_eq!(jazz("Cdim7"), "C°7");
+        assert_eq!(jazz("Cm(maj7)"), "C-Δ7");
+        assert_eq!(jazz("C7b9#11"), "C7♭9♯11");
+        assert_eq!(jazz("G7alt"), "G7alt");
+    }
+
+    #[test]
+    fn berklee_style_spells_out_and_groups_alterations() {
+        let berklee = |symbol| styled(symbol, ChordSymbolStyle::Berklee);
+
+        assert_eq!(berklee("Cmaj7"), "CMaj7");
+        assert_eq!(berklee("Cm(maj7)"), "C-(Maj7)");
+        // no half-diminished or diminished symbols
+        assert_eq!(berklee("Bm7b5"), "B-7(♭5)");
+        assert_eq!(berklee("Cdim7"), "Co7");
+        assert_eq!(berklee("C7b9#11"), "C7(♭9, ♯11)");
+    }
+
+    #[test]
+    fn lilypond_style_uses_note_names_and_step_modifiers() {
+        let lilypond = |symbol| styled(symbol, ChordSymbolStyle::LilyPond);
+
+        assert_eq!(lilypond("C"), "c");
+        assert_eq!(lilypond("Cmaj7"), "c:maj7");
+        assert_eq!(lilypond("Ebm7"), "es:m7");
+        assert_eq!(lilypond("Bm7b5"), "b:m7.5-");
+        assert_eq!(lilypond("Cdim7"), "c:dim7");
+        assert_eq!(lilypond("Cm(maj7)"), "c:m7+");
+        assert_eq!(lilypond("C7b9#11"), "c:7.9-.11+");
+        // `alt` is written out tone by tone
+        assert_eq!(lilypond("G7alt"), "g:7.9-.9+.11+.13-");
+    }
+
+    #[test]
+    fn musicxml_style_names_the_kind() {
+        let musicxml = |symbol| styled(symbol, ChordSymbolStyle::MusicXml);
+
+        assert_eq!(musicxml("C"), "C major");
+        assert_eq!(musicxml("Cmaj7"), "C major-seventh");
+        assert_eq!(musicxml("Ebm7"), "Eb minor-seventh");
+        assert_eq!(musicxml("Bm7b5"), "B half-diminished");
+        assert_eq!(musicxml("Cdim7"), "C diminished-seventh");
+        assert_eq!(musicxml("Cm(maj7)"), "C major-minor");
+        // (Note: tensions are left to `<degree>` elements, the kind stays `dominant`)
+        assert_eq!(musicxml("C7b9#11"), "C dominant");
+        assert_eq!(musicxml("G7alt"), "G dominant");
+    }
+
+    #[test]
+    fn standard_style_keeps_the_display_quality() {
+        for symbol in ["C", "Cmaj7", "Bm7b5", "Cm(maj7)", "C7b9#11", "G7alt"] {
+            let chord_name = ChordName::try_from(symbol).expect("should parse the chord symbol");
+
+            assert!(chord_name
+                .format(ChordSymbolStyle::Standard)
+                .ends_with(&chord_name.get_quality().to_string()));
+        }
+
+        // the root is spelled by letter rather than as a pitch class (ex: not `D#/Ebmin7`)
+        assert_eq!(styled("Ebm7", ChordSymbolStyle::Standard), "Ebmin7");
+    }
+
+    #[test]
+    fn extensions_are_raised_in_superscript() {
+        let chord_name = ChordName::try_from("Ebm7").expect("should parse the chord symbol");
+        assert_eq!(chord_name.format(ChordSymbolStyle::Superscript), "E♭m⁷");
+
+        let chord_name = ChordName::try_from("Cmaj7").expect("should parse the chord symbol");
+        assert_eq!(chord_name.format(ChordSymbolStyle::Superscript), "Cᵐᵃʲ⁷");
+    }
+
+    #[test]
+    fn slash_basses_follow_the_chord_spelling() {
+        // (symbol, jazz, lilypond)
+        let slash_chords = [
+            ("C/E", "C/E", "c/e"),
+            ("Cmaj7/B", "CΔ7/B", "c:maj7/b"),
+            ("Ebm7/Gb", "E♭-7/G♭", "es:m7/ges"),
+        ];
+
+        for (symbol, jazz, lilypond) in slash_chords {
+            let slash_chord = SlashChord::try_from(symbol).expect("should parse the slash chord");
+
+            assert_eq!(slash_chord.format(ChordSymbolStyle::Jazz), jazz);
+            assert_eq!(slash_chord.format(ChordSymbolStyle::LilyPond), lilypond);
+        }
+    }
+
+    #[test]
+    fn written_roots_keep_their_letters() {
+        let chord = SpelledChord::try_from("Bbm7b5").expect("should parse the chord symbol");
+        assert_eq!(chord.format(ChordSymbolStyle::Jazz), "B♭ø7");
+        assert_eq!(chord.format(ChordSymbolStyle::Pop), "Bbm7b5");
+        assert_eq!(chord.format(ChordSymbolStyle::LilyPond), "bes:m7.5-");
+        assert_eq!(
+            chord.format(ChordSymbolStyle::MusicXml),
+            "Bb half-diminished"
+        );
+        // (Note: the pitch-class chord alone takes the fewest accidentals)
+        assert_eq!(chord.chord().chord().format(ChordSymbolStyle::Jazz), "A♯ø7");
+
+        let chord = SpelledChord::try_from("Gb7/Db").expect("should parse the slash chord");
+        assert_eq!(chord.format(ChordSymbolStyle::Jazz), "G♭7/D♭");
+        assert_eq!(chord.format(ChordSymbolStyle::LilyPond), "ges:7/des");
+
+        let chord = SpelledChord::try_from("A#m7").expect("should parse the chord symbol");
+        assert_eq!(chord.format(ChordSymbolStyle::Berklee), "A♯-7");
+        assert_eq!(chord.format(ChordSymbolStyle::LilyPond), "ais:m7");
+    }
+}
@@ -0,0 +1,521 @@
+use crate::chords::ChordNameError;
+use crate::{Accidental, AlteredBase, ChordQuality, Letter, NoteName, SpelledNote, Tensions};
+
+// === CHORD SYMBOL GRAMMAR === //
+//
+// symbol     = root [quality] [extension] [suspension] {modifier} ["/" bass]
+//              (Note: a missing suspension may also come among the modifiers, ex: `C7b9sus4`)
+// root       = note | note "/" note            (ex: `C#/Db` - both names of one pitch)
+// bass       = root                            (ex: `Cmaj/E`, `Cmaj/D#/Eb`)
+// note       = letter [accidental]             (letters in either case, `#` `♯` `b` `♭`)
+// quality    = major
+//            | ("min" | "mi" | "m" | "-" | "aug" | "+") [major | "(" major [extension] ")"]
+//            | "dim" | "°" | "o" | "ø" | "Ø" | "dom"
+// major      = "maj" | "Maj" | "ma" | "Ma" | "M" | "Δ" | "^"
+// extension  = "6/9" | "69" | "6" | "7" | "9" | "11" | "13"
+// suspension = "sus2" | "sus4" | "sus11" | "sus" | "s2" | "s4" | "s11"
+// modifier   = "add" degree | alteration | "alt" | "(" modifier {[","] modifier} ")"
+// alteration = ("b" | "♭" | "#" | "♯") ("5" | "9" | "11" | "13")
+
+// a parsed chord symbol (ex: `Ebmaj7/G` -> `D#/Eb` root, `maj7` quality, `G` bass)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ChordSymbol {
+    pub(crate) root: NoteName,
+    // the root's letter as written - `None` for roots written with both names (ex: `C#/Db`)
+    pub(crate) spelled_root: Option<SpelledNote>,
+    pub(crate) quality: ChordQuality,
+    pub(crate) bass: Option<NoteName>,
+    pub(crate) spelled_bass: Option<SpelledNote>,
+    // byte position of the `/` before the bass note
+    pub(crate) bass_position: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Triad {
+    Minor,
+    Augmented,
+    Diminished,
+    HalfDiminished,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Extension {
+    Six,
+    SixNine,
+    Seventh,
+    Ninth,
+    Eleventh,
+    Thirteenth,
+}
+
+// every quality token found after the root, before deciding on a `ChordQuality`
+#[derive(Debug, Default)]
+struct QualityTokens {
+    // `None` for an unmarked (major / dominant) chord
+    triad: Option<Triad>,
+    // `maj`, `M`, `Δ` or `^` (a major seventh once an extension follows)
+    major: bool,
+    dominant: bool,
+    extension: Option<Extension>,
+    suspension: Option<usize>,
+    added: Vec<usize>,
+    // `(semitones, degree)` - ex: `(-1, 9)` for `b9`
+    alterations: Vec<(i32, usize)>,
+    altered: bool,
+}
+
+impl QualityTokens {
+    fn quality(&self) -> Option<ChordQuality> {
+        if self.dominant && self.extension.is_none() {
+            return None;
+        }
+
+        let mut tensions = Tensions::default();
+        for (semitones, degree) in &self.alterations {
+            tensions = tensions | Tensions::from_alteration(*semitones, *degree)?;
+        }
+        if self.altered {
+            tensions = tensions | Tensions::ALT;
+        }
+        // a lone `alt` is a dominant seventh (ex: `Galt` -> `G7alt`)
+        let extension = match (self.extension, self.altered) {
+            (None, true) => Some(Extension::Seventh),
+            (extension, _) => extension,
+        };
+
+        let base = match (
+            self.triad,
+            self.major,
+            extension,
+            self.suspension,
+            self.added.as_slice(),
+        ) {
+            (None, _, None, None, []) => ChordQuality::Maj,
+            (Some(Triad::Minor), false, None, None, []) => ChordQuality::Min,
+            (Some(Triad::Augmented), false, None, None, []) => ChordQuality::Aug,
+            (Some(Triad::Diminished), false, None, None, []) => ChordQuality::Dim,
+            // `ø` alone is already the half-diminished seventh chord
+            (Some(Triad::HalfDiminished), false, None | Some(Extension::Seventh), None, []) => {
+                ChordQuality::HalfDim
+            }
+            (Some(Triad::Diminished), false, Some(Extension::Seventh), None, []) => {
+                ChordQuality::DimSev
+            }
+            (Some(Triad::Minor), true, Some(Extension::Seventh), None, []) => {
+                ChordQuality::MinMajSev
+            }
+            (Some(Triad::Augmented), true, Some(Extension::Seventh), None, []) => {
+                ChordQuality::AugMajSev
+            }
+            (Some(Triad::Augmented), false, Some(Extension::Seventh), None, []) => {
+                ChordQuality::AugSev
+            }
+            (None, false, None, Some(2), []) => ChordQuality::Sus2,
+            (None, false, None, Some(4), []) => ChordQuality::Sus4,
+            (None, _, None, None, [2 | 9]) => ChordQuality::AddNine,
+            (Some(Triad::Minor), false, None, None, [2 | 9]) => ChordQuality::MinAddNine,
+            (None, _, Some(Extension::Six), None, []) => ChordQuality::Six,
+            (Some(Triad::Minor), false, Some(Extension::Six), None, []) => ChordQuality::MinSix,
+            (None, _, Some(Extension::SixNine), None, []) => ChordQuality::SixNine,
+            (Some(Triad::Minor), false, Some(Extension::SixNine), None, []) => {
+                ChordQuality::MinSixNine
+            }
+            (None, true, Some(Extension::Seventh), None, []) => ChordQuality::MajSev,
+            (Some(Triad::Minor), false, Some(Extension::Seventh), None, []) => ChordQuality::MinSev,
+            (None, false, Some(Extension::Seventh), None, []) => ChordQuality::Sev,
+            // Note: `Csus11` and `C7s11` have long stood in for `C7sus4`
+            (None, false, Some(Extension::Seventh), Some(4 | 11), [])
+            | (None, false, None, Some(11), []) => ChordQuality::SevSus,
+            (None, false, Some(Extension::Ninth), Some(4), []) => ChordQuality::NineSus,
+            (None, false, Some(Extension::Thirteenth), Some(4), []) => ChordQuality::ThirteenSus,
+            (None, true, Some(Extension::Ninth), None, []) => ChordQuality::MajNine,
+            (Some(Triad::Minor), false, Some(Extension::Ninth), None, []) => ChordQuality::MinNine,
+            (None, false, Some(Extension::Ninth), None, []) => ChordQuality::Nine,
+            (None, false, Some(Extension::Eleventh), None, []) => ChordQuality::Eleven,
+            (Some(Triad::Minor), false, Some(Extension::Eleventh), None, []) => {
+                ChordQuality::MinEleven
+            }
+            (None, true, Some(Extension::Thirteenth), None, []) => ChordQuality::MajThirteen,
+            (Some(Triad::Minor), false, Some(Extension::Thirteenth), None, []) => {
+                ChordQuality::MinThirteen
+            }
+            (None, false, Some(Extension::Thirteenth), None, []) => ChordQuality::Thirteen,
+            _ => return None,
+        };
+
+        match (base, tensions) {
+            (_, tensions) if tensions.is_empty() => Some(base),
+            // altered triads are the augmented / diminished triads (ex: `C(#5)`, `Cm(b5)`)
+            (ChordQuality::Maj, Tensions::SHARP_FIVE) => Some(ChordQuality::Aug),
+            (ChordQuality::Min, Tensions::FLAT_FIVE) => Some(ChordQuality::Dim),
+            _ => ChordQuality::altered(AlteredBase::from_quality(&base)?, tensions),
+        }
+    }
+}
+
+pub(crate) fn parse_chord_symbol(input: &str) -> Result<ChordSymbol, ChordNameError> {
+    let mut cursor = Cursor { input, position: 0 };
+
+    let (root, spelled_root) = cursor.note_name()?;
+
+    let quality_position = cursor.position;
+    let tokens = cursor.quality_tokens()?;
+    let quality_end = cursor.position;
+
+    let mut bass = None;
+    let mut spelled_bass = None;
+    let mut bass_position = None;
+    if cursor.rest().starts_with('/') {
+        bass_position = Some(cursor.position);
+        cursor.eat("/");
+        let (note, spelled) = cursor.note_name()?;
+        bass = Some(note);
+        spelled_bass = spelled;
+    }
+
+    if !cursor.rest().is_empty() {
+        return Err(cursor.error(format!("unexpected `{}`", cursor.rest())));
+    }
+
+    let quality = tokens.quality().ok_or_else(|| {
+        ChordNameError::new(
+            input,
+            quality_position,
+            format!(
+                "unsupported chord quality `{}`",
+                &input[quality_position..quality_end]
+            ),
+        )
+    })?;
+
+    Ok(ChordSymbol {
+        root,
+        spelled_root,
+        quality,
+        bass,
+        spelled_bass,
+        bass_position,
+    })
+}
+
+#[derive(Debug, Clone)]
+struct Cursor<'a> {
+    input: &'a str,
+    // byte offset into `input`
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            return true;
+        }
+
+        false
+    }
+
+    // first matching token (list longer tokens ahead of their prefixes)
+    fn eat_any(&mut self, tokens: &[&'static str]) -> Option<&'static str> {
+        tokens.iter().copied().find(|token| self.eat(token))
+    }
+
+    fn error(&self, reason: String) -> ChordNameError {
+        ChordNameError::new(self.input, self.position, reason)
+    }
+
+    fn note(&mut self) -> Result<SpelledNote, ChordNameError> {
+        let letter = match self.rest().chars().next().map(|c| c.to_ascii_uppercase()) {
+            Some('C') => Letter::C,
+            Some('D') => Letter::D,
+            Some('E') => Letter::E,
+            Some('F') => Letter::F,
+            Some('G') => Letter::G,
+            Some('A') => Letter::A,
+            Some('B') => Letter::B,
+            _ => return Err(self.error("expected a note letter (`A` to `G`)".to_string())),
+        };
+        self.position += 1;
+
+        let accidental = match self.eat_any(&["#", "♯", "b", "♭"]) {
+            Some("#" | "♯") => Accidental::Sharp,
+            Some(_) => Accidental::Flat,
+            None => Accidental::Natural,
+        };
+
+        Ok(SpelledNote::new(letter, accidental))
+    }
+
+    // `C#/Db` style notes name the same pitch twice (and so keep no letter) - any other `/` note
+    // is a bass note
+    fn note_name(&mut self) -> Result<(NoteName, Option<SpelledNote>), ChordNameError> {
+        let spelled = self.note()?;
+        let note = spelled.note_name();
+
+        let mut dual = self.clone();
+        if dual.eat("/") && dual.note().is_ok_and(|other| other.note_name() == note) {
+            *self = dual;
+            return Ok((note, None));
+        }
+
+        Ok((note, Some(spelled)))
+    }
+
+    fn quality_tokens(&mut self) -> Result<QualityTokens, ChordNameError> {
+        let mut tokens = QualityTokens::default();
+
+        // `ma` / `Ma` would swallow the `a` of an `add` (ex: `Cmadd9` is `m` + `add9`)
+        let quality = match self
+            .rest()
+            .get(1..)
+            .is_some_and(|rest| rest.starts_with("add"))
+        {
+            true => self.eat_any(&["M", "m"]),
+            false => self.eat_any(&[
+                "maj", "Maj", "ma", "Ma", "min", "mi", "M", "m", "Δ", "^", "-", "aug", "+", "dim",
+                "°", "o", "ø", "Ø", "dom",
+            ]),
+        };
+        match quality {
+            Some("maj" | "Maj" | "ma" | "Ma" | "M") => tokens.major = true,
+            // `Δ` / `^` alone already mean a major seventh chord
+            Some("Δ" | "^") => {
+                tokens.major = true;
+                tokens.extension = Some(Extension::Seventh);
+            }
+            Some("min" | "mi" | "m" | "-") => {
+                tokens.triad = Some(Triad::Minor);
+                self.major_seventh(&mut tokens);
+            }
+            Some("aug" | "+") => {
+                tokens.triad = Some(Triad::Augmented);
+                self.major_seventh(&mut tokens);
+            }
+            Some("dim" | "°" | "o") => tokens.triad = Some(Triad::Diminished),
+            Some("ø" | "Ø") => tokens.triad = Some(Triad::HalfDiminished),
+            Some(_) => tokens.dominant = true,
+            None => {}
+        }
+
+        if let Some(extension) = self.extension() {
+            tokens.extension = Some(extension);
+        }
+
+        tokens.suspension = self.suspension();
+
+        self.modifiers(&mut tokens, false)?;
+
+        Ok(tokens)
+    }
+
+    fn suspension(&mut self) -> Option<usize> {
+        match self.eat_any(&["sus2", "sus4", "sus11", "sus", "s2", "s4", "s11"]) {
+            Some("sus2" | "s2") => Some(2),
+            Some("sus11" | "s11") => Some(11),
+            Some(_) => Some(4),
+            None => None,
+        }
+    }
+
+    // major seventh marker after a minor or augmented triad (ex: `mM7`, `m(maj7)`, `+Δ7`)
+    fn major_seventh(&mut self, tokens: &mut QualityTokens) {
+        let mut marker = self.clone();
+        let parens = marker.eat("(");
+
+        let mut extension = match marker.eat_any(&["maj", "Maj", "M", "Δ", "^"]) {
+            Some("Δ" | "^") => Some(Extension::Seventh),
+            Some(_) => None,
+            None => return,
+        };
+        if parens {
+            extension = marker.extension().or(extension);
+            if !marker.eat(")") {
+                return;
+            }
+        }
+
+        tokens.major = true;
+        tokens.extension = extension;
+        *self = marker;
+    }
+
+    fn extension(&mut self) -> Option<Extension> {
+        match self.eat_any(&["6/9", "69", "6", "7", "9", "11", "13"]) {
+            Some("6/9" | "69") => Some(Extension::SixNine),
+            Some("6") => Some(Extension::Six),
+            Some("7") => Some(Extension::Seventh),
+            Some("9") => Some(Extension::Ninth),
+            Some("11") => Some(Extension::Eleventh),
+            Some("13") => Some(Extension::Thirteenth),
+            _ => None,
+        }
+    }
+
+    fn modifiers(
+        &mut self,
+        tokens: &mut QualityTokens,
+        in_parens: bool,
+    ) -> Result<(), ChordNameError> {
+        loop {
+            if in_parens {
+                self.eat(",");
+                self.eat(" ");
+                if self.eat(")") {
+                    return Ok(());
+                }
+            }
+
+            if self.eat("(") {
+                self.modifiers(tokens, true)?;
+            } else if self.eat("alt") {
+                tokens.altered = true;
+            } else if let Some(suspension) = tokens
+                .suspension
+                .is_none()
+                .then(|| self.suspension())
+                .flatten()
+            {
+                // suspensions may also follow the tensions (ex: `C7b9sus4`)
+                tokens.suspension = Some(suspension);
+            } else if self.eat("add") {
+                match self.eat_any(&["2", "4", "9", "11", "13"]) {
+                    Some(degree) => tokens.added.push(degree.parse().unwrap()),
+                    None => return Err(self.error("expected an added degree".to_string())),
+                }
+            } else if let Some(accidental) = self.eat_any(&["b", "♭", "#", "♯"]) {
+                let semitones = if matches!(accidental, "b" | "♭") {
+                    -1
+                } else {
+                    1
+                };
+                match self.eat_any(&["5", "9", "11", "13"]) {
+                    Some(degree) => tokens
+                        .alterations
+                        .push((semitones, degree.parse().unwrap())),
+                    None => return Err(self.error("expected an altered degree".to_string())),
+                }
+            } else if in_parens {
+                return Err(self.error("expected `)`".to_string()));
+            } else {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChordName, ChordQuality, NoteName, SlashChord};
+
+    fn parse(symbol: &str) -> ChordName {
+        ChordName::try_from(symbol)
+            .unwrap_or_else(|err| panic!("`{}` should parse: {}", symbol, err))
+    }
+
+    fn quality(symbol: &str) -> String {
+        parse(symbol).get_quality().to_string()
+    }
+
+    fn error_position(symbol: &str) -> usize {
+        ChordName::try_from(symbol).expect_err(symbol).position()
+    }
+
+    #[test]
+    fn aliases_name_the_same_chord() {
+        let aliases: [&[&str]; 7] = [
+            &["Cmaj7", "cmaj7", "CΔ7", "CΔ", "Cma7"],
+            &["C♯m7", "C#/Db-7"],
+            &["Cmi7", "Cm7", "C-7"],
+            &["D♭ø", "Dbm7b5"],
+            &["Bb7sus4", "Bb7s4", "A#/Bb7sus4"],
+            &["Cmadd9", "Cm(add9)"],
+            &["Galt", "G7alt"],
+        ];
+
+        for symbols in aliases {
+            let expected = parse(symbols[0]);
+
+            for symbol in &symbols[1..] {
+                assert_eq!(parse(symbol), expected, "`{}` as `{}`", symbol, symbols[0]);
+            }
+        }
+    }
+
+    #[test]
+    fn symbols_parse() {
+        assert_eq!(parse("C"), ChordName::C(ChordQuality::Maj));
+        assert_eq!(parse("C♯m7").get_root(), NoteName::CSharpDFlat);
+        assert_eq!(parse("Ebm7b5").get_root(), NoteName::DSharpEFlat);
+        assert_eq!(parse("a°7").get_root(), NoteName::A);
+
+        assert_eq!(quality("D♭ø"), "min7b5");
+        assert_eq!(quality("Cmadd9"), "min(add9)");
+        // a capital `M` keeps the third major
+        assert_eq!(quality("CMadd9"), "add9");
+        assert_eq!(quality("Cm(maj7)"), "min(maj7)");
+        assert_eq!(quality("Bb7sus4"), "7sus4");
+        assert_eq!(quality("G7b9"), "7b9");
+        assert_eq!(quality("G7(b9,#11)"), "7b9#11");
+        assert_eq!(quality("Galt"), "7alt");
+        assert_eq!(quality("F6/9"), "6/9");
+        assert_eq!(quality("a°7"), "°7");
+    }
+
+    #[test]
+    fn slash_basses_parse() {
+        let chord = SlashChord::try_from("Ebmaj7/G").unwrap();
+        assert_eq!(chord.chord(), ChordName::try_from("Ebmaj7").unwrap());
+        assert_eq!(chord.bass(), NoteName::G);
+
+        let chord = SlashChord::try_from("C#/Dbm/E").unwrap();
+        assert_eq!(chord.chord().get_root(), NoteName::CSharpDFlat);
+        assert_eq!(chord.bass(), NoteName::E);
+    }
+
+    #[test]
+    fn errors_report_byte_positions() {
+        assert_eq!(error_position(""), 0);
+        assert_eq!(error_position("H7"), 0);
+        assert_eq!(error_position("Cmaj7x"), 5);
+        // `♯` takes three bytes
+        assert_eq!(error_position("C♯m7x"), 6);
+        assert_eq!(error_position("Cadd"), 4);
+        assert_eq!(error_position("C7(b9"), 5);
+        assert_eq!(error_position("Cm/X"), 3);
+    }
+
+    #[test]
+    fn suspensions_combine_with_extensions() {
+        for symbol in ["C9sus4", "C9sus", "C9s4"] {
+            let chord = ChordName::try_from(symbol).unwrap();
+            assert_eq!(chord.to_string(), "C9sus4", "`{}`", symbol);
+        }
+
+        let chord = ChordName::try_from("F13sus4").unwrap();
+        assert_eq!(
+            ChordName::try_from(chord.to_string().as_str()).unwrap(),
+            chord
+        );
+        let spelled: Vec<String> = chord
+            .spell(None)
+            .unwrap()
+            .iter()
+            .map(|note| note.to_string())
+            .collect();
+        assert_eq!(spelled, ["F", "Bb", "Eb", "G", "D"]);
+
+        // `7b9sus4` still reads as an altered `7sus4`
+        let chord = ChordName::try_from("D7b9sus4").unwrap();
+        assert_eq!(chord.get_quality().to_string(), "7sus4b9");
+
+        // (Note: no suspended second on extended chords yet - they are reported, not misread)
+        for symbol in ["C7sus2", "C9sus2", "Cmaj9sus4"] {
+            let err = ChordName::try_from(symbol).expect_err(symbol);
+            assert!(err.reason().contains("quality"), "`{}`: {}", symbol, err);
+        }
+    }
+}
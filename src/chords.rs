@@ -1,9 +1,40 @@
-use crate::NoteName;
+use crate::chord_symbols::parse_chord_symbol;
+use crate::keys::{fewest_accidentals_root, spell_degrees};
+use crate::transpose::{Transpose, TransposeError};
+use crate::{music_theory_baux, Interval, Key, NoteName, SpelledNote};
 use std::fmt::{Display, Formatter};
+use std::ops::BitOr;
 
 #[derive(Debug, thiserror::Error)]
-#[error("Error trying in conversion of `&str` chord name `{0}` to `ChordName` enum variant \n(Note: all chords spelled with capitalized `name` letter + various common quality symbols and no spaces [ex: `C`, `C#°` or `C#/Dbdim`, `Db+`, etc.]")]
-pub struct ChordNameError(String);
+#[error("Error trying in conversion of `&str` chord name `{input}` to `ChordName` enum variant at byte `{position}`: {reason} \n(Note: chords are a root letter [ex: `C`, `c`, `C#`, `D♭` or `C#/Db`] + common quality symbols and no spaces [ex: `Cmaj7`, `C♯m7`, `Cmi7`, `CΔ7`, `C°`, `Db+`, etc.]")]
+pub struct ChordNameError {
+    input: String,
+    position: usize,
+    reason: String,
+}
+
+impl ChordNameError {
+    pub(crate) fn new(input: &str, position: usize, reason: String) -> Self {
+        Self {
+            input: input.to_string(),
+            position,
+            reason,
+        }
+    }
+
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    // byte offset into `input` where parsing failed
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ChordQuality {
@@ -17,6 +48,267 @@ pub enum ChordQuality {
     MinSev,
     Sev,
     SevSus,
+    HalfDim,
+    DimSev,
+    MinMajSev,
+    AugMajSev,
+    AugSev,
+    Six,
+    MinSix,
+    SixNine,
+    MinSixNine,
+    AddNine,
+    MinAddNine,
+    Nine,
+    MajNine,
+    MinNine,
+    NineSus,
+    Eleven,
+    MinEleven,
+    Thirteen,
+    MajThirteen,
+    MinThirteen,
+    ThirteenSus,
+    // seventh-family chord + altered tensions (ex: `7b9#5`, `maj7#11`, `7alt`)
+    Altered(AlteredBase, Tensions),
+}
+
+impl ChordQuality {
+    // `base` with `tensions` applied - `None` when a tension repeats the base's own extension
+    // (ex: `9b9`) or doubles another chord tone (ex: `7b5#11`, `7#5b13`, `min7#9`), plain `base`
+    // for empty `tensions`
+    pub fn altered(base: AlteredBase, tensions: Tensions) -> Option<Self> {
+        // altered fifths with their own seventh chord names (ex: `min7b5` -> `HalfDim`)
+        match (base, tensions) {
+            (_, tensions) if tensions.is_empty() => Some(base.quality()),
+            (AlteredBase::MinSev, Tensions::FLAT_FIVE) => Some(ChordQuality::HalfDim),
+            (AlteredBase::Sev, Tensions::SHARP_FIVE) => Some(ChordQuality::AugSev),
+            (AlteredBase::MajSev, Tensions::SHARP_FIVE) => Some(ChordQuality::AugMajSev),
+            _ => {
+                let quality = ChordQuality::Altered(base, tensions);
+                let mut pitch_classes = quality
+                    .formula()
+                    .iter()
+                    .map(|(_, semitones)| semitones % 12)
+                    .collect::<Vec<_>>();
+                let tones = pitch_classes.len();
+                pitch_classes.sort();
+                pitch_classes.dedup();
+
+                (base.allows(tensions) && pitch_classes.len() == tones).then_some(quality)
+            }
+        }
+    }
+
+    // every quality, including each allowed set of altered tensions
+    pub fn all() -> Vec<Self> {
+        let mut qualities = vec![
+            ChordQuality::Maj,
+            ChordQuality::Min,
+            ChordQuality::Aug,
+            ChordQuality::Dim,
+            ChordQuality::Sus2,
+            ChordQuality::Sus4,
+            ChordQuality::MajSev,
+            ChordQuality::MinSev,
+            ChordQuality::Sev,
+            ChordQuality::SevSus,
+            ChordQuality::HalfDim,
+            ChordQuality::DimSev,
+            ChordQuality::MinMajSev,
+            ChordQuality::AugMajSev,
+            ChordQuality::AugSev,
+            ChordQuality::Six,
+            ChordQuality::MinSix,
+            ChordQuality::SixNine,
+            ChordQuality::MinSixNine,
+            ChordQuality::AddNine,
+            ChordQuality::MinAddNine,
+            ChordQuality::Nine,
+            ChordQuality::MajNine,
+            ChordQuality::MinNine,
+            ChordQuality::NineSus,
+            ChordQuality::Eleven,
+            ChordQuality::MinEleven,
+            ChordQuality::Thirteen,
+            ChordQuality::MajThirteen,
+            ChordQuality::MinThirteen,
+            ChordQuality::ThirteenSus,
+        ];
+
+        for base in AlteredBase::ALL {
+            qualities.extend(
+                Tensions::all()
+                    .into_iter()
+                    .filter_map(|tensions| ChordQuality::altered(base, tensions))
+                    .filter(|quality| matches!(quality, ChordQuality::Altered(..))),
+            );
+        }
+
+        qualities
+    }
+
+    // every chord tone as `(degree, semitones above the root)`, lowest to highest
+    pub fn formula(&self) -> Vec<(ChordToneDegree, usize)> {
+        use ChordToneDegree::*;
+
+        match self {
+            ChordQuality::Maj => vec![(Root, 0), (Third, 4), (Fifth, 7)],
+            ChordQuality::Min => vec![(Root, 0), (Third, 3), (Fifth, 7)],
+            ChordQuality::Aug => vec![(Root, 0), (Third, 4), (Fifth, 8)],
+            ChordQuality::Dim => vec![(Root, 0), (Third, 3), (Fifth, 6)],
+            ChordQuality::Sus2 => vec![(Root, 0), (Second, 2), (Fifth, 7)],
+            ChordQuality::Sus4 => vec![(Root, 0), (Fourth, 5), (Fifth, 7)],
+            ChordQuality::MajSev => vec![(Root, 0), (Third, 4), (Fifth, 7), (Seventh, 11)],
+            ChordQuality::MinSev => vec![(Root, 0), (Third, 3), (Fifth, 7), (Seventh, 10)],
+            ChordQuality::Sev => vec![(Root, 0), (Third, 4), (Fifth, 7), (Seventh, 10)],
+            ChordQuality::SevSus => vec![(Root, 0), (Fourth, 5), (Fifth, 7), (Seventh, 10)],
+            ChordQuality::HalfDim => vec![(Root, 0), (Third, 3), (Fifth, 6), (Seventh, 10)],
+            // Note: the diminished seventh (`bb7`) is spelled as a seventh (ex: `C` -> `Bbb`)
+            ChordQuality::DimSev => vec![(Root, 0), (Third, 3), (Fifth, 6), (Seventh, 9)],
+            ChordQuality::MinMajSev => vec![(Root, 0), (Third, 3), (Fifth, 7), (Seventh, 11)],
+            ChordQuality::AugMajSev => vec![(Root, 0), (Third, 4), (Fifth, 8), (Seventh, 11)],
+            ChordQuality::AugSev => vec![(Root, 0), (Third, 4), (Fifth, 8), (Seventh, 10)],
+            ChordQuality::Six => vec![(Root, 0), (Third, 4), (Fifth, 7), (Sixth, 9)],
+            ChordQuality::MinSix => vec![(Root, 0), (Third, 3), (Fifth, 7), (Sixth, 9)],
+            ChordQuality::SixNine => {
+                vec![(Root, 0), (Third, 4), (Fifth, 7), (Sixth, 9), (Ninth, 14)]
+            }
+            ChordQuality::MinSixNine => {
+                vec![(Root, 0), (Third, 3), (Fifth, 7), (Sixth, 9), (Ninth, 14)]
+            }
+            ChordQuality::AddNine => vec![(Root, 0), (Third, 4), (Fifth, 7), (Ninth, 14)],
+            ChordQuality::MinAddNine => vec![(Root, 0), (Third, 3), (Fifth, 7), (Ninth, 14)],
+            ChordQuality::Nine => {
+                vec![
+                    (Root, 0),
+                    (Third, 4),
+                    (Fifth, 7),
+                    (Seventh, 10),
+                    (Ninth, 14),
+                ]
+            }
+            ChordQuality::MajNine => {
+                vec![
+                    (Root, 0),
+                    (Third, 4),
+                    (Fifth, 7),
+                    (Seventh, 11),
+                    (Ninth, 14),
+                ]
+            }
+            ChordQuality::MinNine => {
+                vec![
+                    (Root, 0),
+                    (Third, 3),
+                    (Fifth, 7),
+                    (Seventh, 10),
+                    (Ninth, 14),
+                ]
+            }
+            ChordQuality::NineSus => {
+                vec![
+                    (Root, 0),
+                    (Fourth, 5),
+                    (Fifth, 7),
+                    (Seventh, 10),
+                    (Ninth, 14),
+                ]
+            }
+            ChordQuality::Eleven => vec![
+                (Root, 0),
+                (Third, 4),
+                (Fifth, 7),
+                (Seventh, 10),
+                (Ninth, 14),
+                (Eleventh, 17),
+            ],
+            ChordQuality::MinEleven => vec![
+                (Root, 0),
+                (Third, 3),
+                (Fifth, 7),
+                (Seventh, 10),
+                (Ninth, 14),
+                (Eleventh, 17),
+            ],
+            // Note: the (clashing) natural 11th is left out of major & dominant 13th chords
+            ChordQuality::Thirteen => vec![
+                (Root, 0),
+                (Third, 4),
+                (Fifth, 7),
+                (Seventh, 10),
+                (Ninth, 14),
+                (Thirteenth, 21),
+            ],
+            ChordQuality::MajThirteen => vec![
+                (Root, 0),
+                (Third, 4),
+                (Fifth, 7),
+                (Seventh, 11),
+                (Ninth, 14),
+                (Thirteenth, 21),
+            ],
+            ChordQuality::MinThirteen => vec![
+                (Root, 0),
+                (Third, 3),
+                (Fifth, 7),
+                (Seventh, 10),
+                (Ninth, 14),
+                (Eleventh, 17),
+                (Thirteenth, 21),
+            ],
+            ChordQuality::ThirteenSus => vec![
+                (Root, 0),
+                (Fourth, 5),
+                (Fifth, 7),
+                (Seventh, 10),
+                (Ninth, 14),
+                (Thirteenth, 21),
+            ],
+            ChordQuality::Altered(base, tensions) => {
+                let alterations = tensions.alterations();
+                let mut formula = base.quality().formula();
+
+                // altered tones replace their unaltered degree (ex: `b9` replaces the `9`)
+                // (Note: `alt` also drops the natural fifth - its `#11` and `b13` take its place)
+                let is_alt = tensions.contains(Tensions::ALT);
+                formula.retain(|(degree, _)| {
+                    let replaced = alterations.iter().any(|(altered, _)| altered == degree);
+                    let dropped = is_alt && *degree == ChordToneDegree::Fifth;
+                    !replaced && !dropped
+                });
+                formula.extend(alterations);
+                formula.sort_by_key(|(_, semitones)| *semitones);
+
+                formula
+            }
+        }
+    }
+
+    // tones kept in a chord spelling of `form` - shells leave out an unaltered fifth on seventh
+    // chords, dominant 11th shells also leave out the third (ex: `C11` -> `C`, `Bb`, `D`, `F`)
+    pub fn spelling_formula(&self, form: SpellingForm) -> Vec<(ChordToneDegree, usize)> {
+        if form == SpellingForm::Complete {
+            return self.formula();
+        }
+
+        let mut formula = self.formula();
+        let has_seventh = formula
+            .iter()
+            .any(|(degree, _)| *degree == ChordToneDegree::Seventh);
+        let is_eleven = matches!(
+            self,
+            ChordQuality::Eleven | ChordQuality::Altered(AlteredBase::Eleven, _)
+        );
+
+        formula.retain(|tone| match tone {
+            (ChordToneDegree::Fifth, 7) => !has_seventh,
+            (ChordToneDegree::Third, _) => !is_eleven,
+            _ => true,
+        });
+
+        formula
+    }
 }
 
 impl Display for ChordQuality {
@@ -32,10 +324,198 @@ impl Display for ChordQuality {
             ChordQuality::MinSev => write!(f, "min7"),
             ChordQuality::Sev => write!(f, "7"),
             ChordQuality::SevSus => write!(f, "7sus4"),
+            ChordQuality::HalfDim => write!(f, "min7b5"),
+            ChordQuality::DimSev => write!(f, "°7"),
+            ChordQuality::MinMajSev => write!(f, "min(maj7)"),
+            ChordQuality::AugMajSev => write!(f, "+maj7"),
+            ChordQuality::AugSev => write!(f, "+7"),
+            ChordQuality::Six => write!(f, "6"),
+            ChordQuality::MinSix => write!(f, "min6"),
+            ChordQuality::SixNine => write!(f, "6/9"),
+            ChordQuality::MinSixNine => write!(f, "min6/9"),
+            ChordQuality::AddNine => write!(f, "add9"),
+            ChordQuality::MinAddNine => write!(f, "min(add9)"),
+            ChordQuality::Nine => write!(f, "9"),
+            ChordQuality::MajNine => write!(f, "maj9"),
+            ChordQuality::MinNine => write!(f, "min9"),
+            ChordQuality::NineSus => write!(f, "9sus4"),
+            ChordQuality::Eleven => write!(f, "11"),
+            ChordQuality::MinEleven => write!(f, "min11"),
+            ChordQuality::Thirteen => write!(f, "13"),
+            ChordQuality::MajThirteen => write!(f, "maj13"),
+            ChordQuality::MinThirteen => write!(f, "min13"),
+            ChordQuality::ThirteenSus => write!(f, "13sus4"),
+            ChordQuality::Altered(base, tensions) => write!(f, "{}{}", base.quality(), tensions),
         }
     }
 }
 
+// seventh-family qualities that can take altered tensions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlteredBase {
+    Sev,
+    MajSev,
+    MinSev,
+    SevSus,
+    Nine,
+    MajNine,
+    MinNine,
+    Eleven,
+    MinEleven,
+    Thirteen,
+    MajThirteen,
+    MinThirteen,
+}
+
+impl AlteredBase {
+    pub const ALL: [AlteredBase; 12] = [
+        AlteredBase::Sev,
+        AlteredBase::MajSev,
+        AlteredBase::MinSev,
+        AlteredBase::SevSus,
+        AlteredBase::Nine,
+        AlteredBase::MajNine,
+        AlteredBase::MinNine,
+        AlteredBase::Eleven,
+        AlteredBase::MinEleven,
+        AlteredBase::Thirteen,
+        AlteredBase::MajThirteen,
+        AlteredBase::MinThirteen,
+    ];
+
+    pub fn quality(&self) -> ChordQuality {
+        match self {
+            AlteredBase::Sev => ChordQuality::Sev,
+            AlteredBase::MajSev => ChordQuality::MajSev,
+            AlteredBase::MinSev => ChordQuality::MinSev,
+            AlteredBase::SevSus => ChordQuality::SevSus,
+            AlteredBase::Nine => ChordQuality::Nine,
+            AlteredBase::MajNine => ChordQuality::MajNine,
+            AlteredBase::MinNine => ChordQuality::MinNine,
+            AlteredBase::Eleven => ChordQuality::Eleven,
+            AlteredBase::MinEleven => ChordQuality::MinEleven,
+            AlteredBase::Thirteen => ChordQuality::Thirteen,
+            AlteredBase::MajThirteen => ChordQuality::MajThirteen,
+            AlteredBase::MinThirteen => ChordQuality::MinThirteen,
+        }
+    }
+
+    pub fn from_quality(quality: &ChordQuality) -> Option<Self> {
+        AlteredBase::ALL
+            .into_iter()
+            .find(|base| base.quality() == *quality)
+    }
+
+    // the base's own extension can't also be altered (ex: no `b9` on a `9` chord)
+    fn allows(&self, tensions: Tensions) -> bool {
+        let extension = match self {
+            AlteredBase::Nine | AlteredBase::MajNine | AlteredBase::MinNine => {
+                Tensions::FLAT_NINE | Tensions::SHARP_NINE
+            }
+            AlteredBase::Eleven | AlteredBase::MinEleven => Tensions::SHARP_ELEVEN,
+            AlteredBase::Thirteen | AlteredBase::MajThirteen | AlteredBase::MinThirteen => {
+                Tensions::FLAT_THIRTEEN
+            }
+            _ => Tensions::default(),
+        };
+
+        !tensions.intersects(extension)
+    }
+}
+
+// set of altered tensions (`b5`, `#5`, `b9`, `#9`, `#11`, `b13`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Tensions(u8);
+
+impl Tensions {
+    pub const FLAT_FIVE: Tensions = Tensions(1);
+    pub const SHARP_FIVE: Tensions = Tensions(1 << 1);
+    pub const FLAT_NINE: Tensions = Tensions(1 << 2);
+    pub const SHARP_NINE: Tensions = Tensions(1 << 3);
+    pub const SHARP_ELEVEN: Tensions = Tensions(1 << 4);
+    pub const FLAT_THIRTEEN: Tensions = Tensions(1 << 5);
+    // `alt` - every altered ninth, `#11` and `b13`
+    pub const ALT: Tensions = Tensions(0b11_1100);
+
+    // `(tension, degree, semitones above the root, symbol)`, lowest to highest
+    const TONES: [(Tensions, ChordToneDegree, usize, &'static str); 6] = [
+        (Tensions::FLAT_FIVE, ChordToneDegree::Fifth, 6, "b5"),
+        (Tensions::SHARP_FIVE, ChordToneDegree::Fifth, 8, "#5"),
+        (Tensions::FLAT_NINE, ChordToneDegree::Ninth, 13, "b9"),
+        (Tensions::SHARP_NINE, ChordToneDegree::Ninth, 15, "#9"),
+        (Tensions::SHARP_ELEVEN, ChordToneDegree::Eleventh, 18, "#11"),
+        (
+            Tensions::FLAT_THIRTEEN,
+            ChordToneDegree::Thirteenth,
+            20,
+            "b13",
+        ),
+    ];
+
+    // single tension for an accidental + degree (ex: `-1`, `9` -> `b9`)
+    pub fn from_alteration(semitones: i32, degree: usize) -> Option<Self> {
+        match (semitones, degree) {
+            (-1, 5) => Some(Tensions::FLAT_FIVE),
+            (1, 5) => Some(Tensions::SHARP_FIVE),
+            (-1, 9) => Some(Tensions::FLAT_NINE),
+            (1, 9) => Some(Tensions::SHARP_NINE),
+            (1, 11) => Some(Tensions::SHARP_ELEVEN),
+            (-1, 13) => Some(Tensions::FLAT_THIRTEEN),
+            _ => None,
+        }
+    }
+
+    // every possible set of tensions (including the empty set)
+    pub fn all() -> Vec<Self> {
+        (0..1 << Tensions::TONES.len()).map(Tensions).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(&self, other: Tensions) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn intersects(&self, other: Tensions) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    // `(degree, semitones above the root)` for each tension in the set
+    pub fn alterations(&self) -> Vec<(ChordToneDegree, usize)> {
+        Tensions::TONES
+            .iter()
+            .filter(|(tension, ..)| self.contains(*tension))
+            .map(|(_, degree, semitones, _)| (*degree, *semitones))
+            .collect()
+    }
+}
+
+impl BitOr for Tensions {
+    type Output = Tensions;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Tensions(self.0 | rhs.0)
+    }
+}
+
+impl Display for Tensions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if *self == Tensions::ALT {
+            return write!(f, "alt");
+        }
+
+        for (tension, _, _, symbol) in Tensions::TONES {
+            if self.contains(tension) {
+                write!(f, "{symbol}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ChordName {
     C(ChordQuality),
@@ -53,6 +533,23 @@ pub enum ChordName {
 }
 
 impl ChordName {
+    pub fn new(root: &NoteName, quality: ChordQuality) -> Self {
+        match root {
+            NoteName::C => ChordName::C(quality),
+            NoteName::CSharpDFlat => ChordName::CSharpDFlat(quality),
+            NoteName::D => ChordName::D(quality),
+            NoteName::DSharpEFlat => ChordName::DSharpEFlat(quality),
+            NoteName::E => ChordName::E(quality),
+            NoteName::F => ChordName::F(quality),
+            NoteName::FSharpGFlat => ChordName::FSharpGFlat(quality),
+            NoteName::G => ChordName::G(quality),
+            NoteName::GSharpAFlat => ChordName::GSharpAFlat(quality),
+            NoteName::A => ChordName::A(quality),
+            NoteName::ASharpBFlat => ChordName::ASharpBFlat(quality),
+            NoteName::B => ChordName::B(quality),
+        }
+    }
+
     pub fn get_root(&self) -> NoteName {
         match self {
             ChordName::C(_) => NoteName::C,
@@ -86,6 +583,46 @@ impl ChordName {
             ChordName::B(qual) => *qual,
         }
     }
+
+    // chord + slash bass note (ex: `Cmaj7/E` -> `Cmaj7`, `Some(E)`)
+    // (Note: a bass matching the root, ex: `C/C`, is plain root position - `None`)
+    pub fn parse_with_bass(value: &str) -> Result<(ChordName, Option<NoteName>), ChordNameError> {
+        let symbol = parse_chord_symbol(value)?;
+        let bass = symbol.bass.filter(|bass| *bass != symbol.root);
+
+        Ok((ChordName::new(&symbol.root, symbol.quality), bass))
+    }
+
+    // letter-based chord tones, spelled in the context of `key` when given
+    pub fn spell(&self, key: Option<&Key>) -> Option<Vec<SpelledNote>> {
+        music_theory_baux
+            .get_chord_spelling(self)
+            .map(|chord_spelling| chord_spelling.spelled_in(key))
+    }
+
+    // chord symbol with a letter-based root (ex: `Ebmaj7` rather than `D#/Ebmaj7`)
+    // (Note: a bare `ChordName` only knows its root's pitch - see `SpelledChord` for the letter
+    // a symbol was written with)
+    pub fn spelled_symbol(&self, key: Option<&Key>) -> String {
+        format!("{}{}", self.spelled_root(key), self.get_quality())
+    }
+
+    // the root's letter in `key`, or the one reading with the fewest accidentals
+    pub(crate) fn spelled_root(&self, key: Option<&Key>) -> SpelledNote {
+        match self.spell(key) {
+            Some(spelled_notes) => spelled_notes[0],
+            None => SpelledNote::from(self.get_root()),
+        }
+    }
+}
+
+impl Transpose for ChordName {
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError> {
+        Ok(ChordName::new(
+            &self.get_root().transpose(semitones)?,
+            self.get_quality(),
+        ))
+    }
 }
 
 impl Display for ChordName {
@@ -111,247 +648,208 @@ impl TryFrom<&str> for ChordName {
     type Error = ChordNameError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "C" | "Cmaj" | "CM" => Ok(ChordName::C(ChordQuality::Maj)),
-            "Cm" | "Cmin" | "C-" => Ok(ChordName::C(ChordQuality::Min)),
-            "Caug" | "C+" => Ok(ChordName::C(ChordQuality::Aug)),
-            "Cdim" | "CØ" | "C°" => Ok(ChordName::C(ChordQuality::Dim)),
-
-            "Csus2" | "Cs2" => Ok(ChordName::C(ChordQuality::Sus2)),
-            "Csus4" | "Csus" | "Cs4" => Ok(ChordName::C(ChordQuality::Sus4)),
-            "Cmaj7" | "CM7" | "C^7" => Ok(ChordName::C(ChordQuality::MajSev)),
-            "Cmin7" | "Cm7" | "C-7" => Ok(ChordName::C(ChordQuality::MinSev)),
-            "C7" | "Cdom7" => Ok(ChordName::C(ChordQuality::Sev)),
-            "C7sus4" | "C7s4" | "C7s11" | "C11" | "Csus11" => {
-                Ok(ChordName::C(ChordQuality::SevSus))
-            }
+        let symbol = parse_chord_symbol(value)?;
 
-            "C#" | "Db" | "C#maj" | "Dbmaj" | "C#/Dbmaj" | "C#M" | "DbM" | "C#/DbM" => {
-                Ok(ChordName::CSharpDFlat(ChordQuality::Maj))
-            }
-            "C#m" | "Dbm" | "C#/Dbm" | "C#min" | "Dbmin" | "C#/Dbmin" | "C#-" | "Db-"
-            | "C#/Db-" => Ok(ChordName::CSharpDFlat(ChordQuality::Min)),
-            "C#aug" | "C#+" | "Dbaug" | "Db+" | "C#/Db+" | "C#/Dbaug" => {
-                Ok(ChordName::CSharpDFlat(ChordQuality::Aug))
+        if let (Some(bass), Some(position)) = (symbol.bass, symbol.bass_position) {
+            if bass != symbol.root {
+                return Err(ChordNameError::new(
+                    value,
+                    position,
+                    format!(
+                        "slash bass `{}` is not part of a `ChordName` (see `SlashChord`)",
+                        &value[position..]
+                    ),
+                ));
             }
-            "C#dim" | "C#Ø" | "C#°" | "Dbdim" | "DbØ" | "Db°" | "C#/Dbdim" | "C#/DbØ"
-            | "C#/Db°" => Ok(ChordName::CSharpDFlat(ChordQuality::Dim)),
+        }
 
-            "C#sus2" | "C#s2" | "C#/Dbsus2" | "C#/Dbs2" | "Dbsus2" | "Dbs2" => {
-                Ok(ChordName::CSharpDFlat(ChordQuality::Sus2))
-            }
-            "C#sus4" | "C#sus" | "C#s4" | "C#/Dbsus4" | "C#/Dbsus" | "C#/Dbs4" | "Dbsus4"
-            | "Dbs4" | "Dbsus" => Ok(ChordName::CSharpDFlat(ChordQuality::Sus4)),
-            "C#maj7" | "C#M7" | "C#^7" | "C#/Dbmaj7" | "C#/DbM7" | "C#/Db^7" | "Dbmaj7"
-            | "DbM7" | "Db^7" => Ok(ChordName::CSharpDFlat(ChordQuality::MajSev)),
-            "C#min7" | "C#m7" | "C#-7" | "C#/Dbmin7" | "C#/Dbm7" | "C#/Db-7" | "Dbmin7"
-            | "Dbm7" | "Db-7" => Ok(ChordName::CSharpDFlat(ChordQuality::MinSev)),
-            "C#7" | "C#dom7" | "C#/Db7" | "C#/Dbdom7" | "Db7" | "Dbdom7" => {
-                Ok(ChordName::CSharpDFlat(ChordQuality::Sev))
-            }
+        Ok(ChordName::new(&symbol.root, symbol.quality))
+    }
+}
 
-            "C#7sus4" | "C#7s4" | "C#7s11" | "C#11" | "C#sus11" | "C#/Db7sus4" | "C#/Db7s4"
-            | "C#/Db7s11" | "C#/Db11" | "C#/Dbsus11" | "Db7sus4" | "Db7s4" | "Db7s11" | "Db11"
-            | "Dbsus11" => Ok(ChordName::CSharpDFlat(ChordQuality::SevSus)),
-
-            "D" | "Dmaj" | "DM" => Ok(ChordName::D(ChordQuality::Maj)),
-            "Dm" | "Dmin" | "D-" => Ok(ChordName::D(ChordQuality::Min)),
-            "Daug" | "D+" => Ok(ChordName::D(ChordQuality::Aug)),
-            "Ddim" | "DØ" | "D°" => Ok(ChordName::D(ChordQuality::Dim)),
-
-            "Dsus2" | "Ds2" => Ok(ChordName::D(ChordQuality::Sus2)),
-            "Dsus4" | "Dsus" | "Ds4" => Ok(ChordName::D(ChordQuality::Sus4)),
-            "Dmaj7" | "DM7" | "D^7" => Ok(ChordName::D(ChordQuality::MajSev)),
-            "Dmin7" | "Dm7" | "D-7" => Ok(ChordName::D(ChordQuality::MinSev)),
-            "D7" | "Ddom7" => Ok(ChordName::D(ChordQuality::Sev)),
-            "D7sus4" | "D7s4" | "D7s11" | "D11" | "Dsus11" => {
-                Ok(ChordName::D(ChordQuality::SevSus))
-            }
+// chord over an explicit bass note (ex: `C/E`, `G7/B`, `Amin/G`)
+// (Note: a bass matching the root is plain root position - displayed without the slash)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SlashChord {
+    chord: ChordName,
+    bass: NoteName,
+}
 
-            "D#" | "Eb" | "D#maj" | "Ebmaj" | "D#/Ebmaj" | "D#M" | "EbM" | "D#/EbM" => {
-                Ok(ChordName::DSharpEFlat(ChordQuality::Maj))
-            }
-            "D#m" | "Ebm" | "D#/Ebm" | "D#min" | "Ebmin" | "D#/Ebmin" | "D#-" | "Eb-"
-            | "D#/Eb-" => Ok(ChordName::DSharpEFlat(ChordQuality::Min)),
-            "D#aug" | "D#+" | "Ebaug" | "Eb+" | "D#/Eb+" | "D#/Ebaug" => {
-                Ok(ChordName::DSharpEFlat(ChordQuality::Aug))
-            }
-            "D#dim" | "D#Ø" | "D#°" | "Ebdim" | "EbØ" | "Eb°" | "D#/Ebdim" | "D#/EbØ"
-            | "D#/Eb°" => Ok(ChordName::DSharpEFlat(ChordQuality::Dim)),
+impl SlashChord {
+    pub fn new(chord: ChordName, bass: &NoteName) -> Self {
+        Self { chord, bass: *bass }
+    }
 
-            "D#sus2" | "D#s2" | "D#/Ebsus2" | "D#/Ebs2" | "Ebsus2" | "Ebs2" => {
-                Ok(ChordName::DSharpEFlat(ChordQuality::Sus2))
-            }
-            "D#sus4" | "D#sus" | "D#s4" | "D#/Ebsus4" | "D#/Ebsus" | "D#/Ebs4" | "Ebsus4"
-            | "Ebs4" | "Ebsus" => Ok(ChordName::DSharpEFlat(ChordQuality::Sus4)),
-            "D#maj7" | "D#M7" | "D#^7" | "D#/Ebmaj7" | "D#/EbM7" | "D#/Eb^7" | "Ebmaj7"
-            | "EbM7" | "Eb^7" => Ok(ChordName::DSharpEFlat(ChordQuality::MajSev)),
-            "D#min7" | "D#m7" | "D#-7" | "D#/Ebmin7" | "D#/Ebm7" | "D#/Eb-7" | "Ebmin7"
-            | "Ebm7" | "Eb-7" => Ok(ChordName::DSharpEFlat(ChordQuality::MinSev)),
-            "D#7" | "D#dom7" | "D#/Eb7" | "D#/Ebdom7" | "Eb7" | "Ebdom7" => {
-                Ok(ChordName::DSharpEFlat(ChordQuality::Sev))
-            }
+    pub fn chord(&self) -> ChordName {
+        self.chord
+    }
 
-            "D#7sus4" | "D#7s4" | "D#7s11" | "D#11" | "D#sus11" | "D#/Eb7sus4" | "D#/Eb7s4"
-            | "D#/Eb7s11" | "D#/Eb11" | "D#/Ebsus11" | "Eb7sus4" | "Eb7s4" | "Eb7s11" | "Eb11"
-            | "Ebsus11" => Ok(ChordName::DSharpEFlat(ChordQuality::SevSus)),
-
-            "E" | "Emaj" | "EM" => Ok(ChordName::E(ChordQuality::Maj)),
-            "Em" | "Emin" | "E-" => Ok(ChordName::E(ChordQuality::Min)),
-            "Eaug" | "E+" => Ok(ChordName::E(ChordQuality::Aug)),
-            "Edim" | "EØ" | "E°" => Ok(ChordName::E(ChordQuality::Dim)),
-
-            "Esus2" | "Es2" => Ok(ChordName::E(ChordQuality::Sus2)),
-            "Esus4" | "Esus" | "Es4" => Ok(ChordName::E(ChordQuality::Sus4)),
-            "Emaj7" | "EM7" | "E^7" => Ok(ChordName::E(ChordQuality::MajSev)),
-            "Emin7" | "Em7" | "E-7" => Ok(ChordName::E(ChordQuality::MinSev)),
-            "E7" | "Edom7" => Ok(ChordName::E(ChordQuality::Sev)),
-            "E7sus4" | "E7s4" | "E7s11" | "E11" | "Esus11" => {
-                Ok(ChordName::E(ChordQuality::SevSus))
-            }
+    pub fn bass(&self) -> NoteName {
+        self.bass
+    }
 
-            "F" | "Fmaj" | "FM" => Ok(ChordName::F(ChordQuality::Maj)),
-            "Fm" | "Fmin" | "F-" => Ok(ChordName::F(ChordQuality::Min)),
-            "Faug" | "F+" => Ok(ChordName::F(ChordQuality::Aug)),
-            "Fdim" | "FØ" | "F°" => Ok(ChordName::F(ChordQuality::Dim)),
-
-            "Fsus2" | "Fs2" => Ok(ChordName::F(ChordQuality::Sus2)),
-            "Fsus4" | "Fsus" | "Fs4" => Ok(ChordName::F(ChordQuality::Sus4)),
-            "Fmaj7" | "FM7" | "F^7" => Ok(ChordName::F(ChordQuality::MajSev)),
-            "Fmin7" | "Fm7" | "F-7" => Ok(ChordName::F(ChordQuality::MinSev)),
-            "F7" | "Fdom7" => Ok(ChordName::F(ChordQuality::Sev)),
-            "F7sus4" | "F7s4" | "F7s11" | "F11" | "Fsus11" => {
-                Ok(ChordName::F(ChordQuality::SevSus))
-            }
+    pub fn is_root_position(&self) -> bool {
+        self.bass == self.chord.get_root()
+    }
 
-            "F#" | "Gb" | "F#maj" | "Gbmaj" | "F#/Gbmaj" | "F#M" | "GbM" | "F#/GbM" => {
-                Ok(ChordName::FSharpGFlat(ChordQuality::Maj))
-            }
-            "F#m" | "Gbm" | "F#/Gbm" | "F#min" | "Gbmin" | "F#/Gbmin" | "F#-" | "Gb-"
-            | "F#/Gb-" => Ok(ChordName::FSharpGFlat(ChordQuality::Min)),
-            "F#aug" | "F#+" | "Gbaug" | "Gb+" | "F#/Gb+" | "F#/Gbaug" => {
-                Ok(ChordName::FSharpGFlat(ChordQuality::Aug))
-            }
-            "F#dim" | "F#Ø" | "F#°" | "Gbdim" | "GbØ" | "Gb°" | "F#/Gbdim" | "F#/GbØ"
-            | "F#/Gb°" => Ok(ChordName::FSharpGFlat(ChordQuality::Dim)),
+    // `0` for root position, `1` for the third in the bass, `2` for the fifth, ... counted
+    // through the complete spelling (ex: `G7/F` -> `3`) - `None` for basses outside the chord
+    pub fn inversion(&self) -> Option<usize> {
+        music_theory_baux
+            .get_chord_spelling_form(&self.chord, SpellingForm::Complete)?
+            .spelling()
+            .iter()
+            .position(|chord_tone| *chord_tone.note() == self.bass)
+    }
 
-            "F#sus2" | "F#s2" | "F#/Gbsus2" | "F#/Gbs2" | "Gbsus2" | "Gbs2" => {
-                Ok(ChordName::FSharpGFlat(ChordQuality::Sus2))
-            }
-            "F#sus4" | "F#sus" | "F#s4" | "F#/Gbsus4" | "F#/Gbsus" | "F#/Gbs4" | "Gbsus4"
-            | "Gbs4" | "Gbsus" => Ok(ChordName::FSharpGFlat(ChordQuality::Sus4)),
-            "F#maj7" | "F#M7" | "F#^7" | "F#/Gbmaj7" | "F#/GbM7" | "F#/Gb^7" | "Gbmaj7"
-            | "GbM7" | "Gb^7" => Ok(ChordName::FSharpGFlat(ChordQuality::MajSev)),
-            "F#min7" | "F#m7" | "F#-7" | "F#/Gbmin7" | "F#/Gbm7" | "F#/Gb-7" | "Gbmin7"
-            | "Gbm7" | "Gb-7" => Ok(ChordName::FSharpGFlat(ChordQuality::MinSev)),
-            "F#7" | "F#dom7" | "F#/Gb7" | "F#/Gbdom7" | "Gb7" | "Gbdom7" => {
-                Ok(ChordName::FSharpGFlat(ChordQuality::Sev))
-            }
-            "F#7sus4" | "F#7s4" | "F#7s11" | "F#11" | "F#sus11" | "F#/Gb7sus4" | "F#/Gb7s4"
-            | "F#/Gb7s11" | "F#/Gb11" | "F#/Gbsus11" | "Gb7sus4" | "Gb7s4" | "Gb7s11" | "Gb11"
-            | "Gbsus11" => Ok(ChordName::FSharpGFlat(ChordQuality::SevSus)),
-
-            "G" | "Gmaj" | "GM" => Ok(ChordName::G(ChordQuality::Maj)),
-            "Gm" | "Gmin" | "G-" => Ok(ChordName::G(ChordQuality::Min)),
-            "Gaug" | "G+" => Ok(ChordName::G(ChordQuality::Aug)),
-            "Gdim" | "GØ" | "G°" => Ok(ChordName::G(ChordQuality::Dim)),
-
-            "Gsus2" | "Gs2" => Ok(ChordName::G(ChordQuality::Sus2)),
-            "Gsus4" | "Gsus" | "Gs4" => Ok(ChordName::G(ChordQuality::Sus4)),
-            "Gmaj7" | "GM7" | "G^7" => Ok(ChordName::G(ChordQuality::MajSev)),
-            "Gmin7" | "Gm7" | "G-7" => Ok(ChordName::G(ChordQuality::MinSev)),
-            "G7" | "Gdom7" => Ok(ChordName::G(ChordQuality::Sev)),
-            "G7sus4" | "G7s4" | "G7s11" | "G11" | "Gsus11" => {
-                Ok(ChordName::G(ChordQuality::SevSus))
-            }
+    // chord spelling of `form` with the bass as an extra `ChordTone::Bass` (ex: `G7/B`)
+    pub fn spelling(&self, form: SpellingForm) -> Option<ChordSpelling> {
+        music_theory_baux
+            .get_chord_spelling_form(&self.chord, form)
+            .map(|chord_spelling| chord_spelling.clone().with_bass(&self.bass))
+    }
 
-            "G#" | "Ab" | "G#maj" | "Abmaj" | "G#/Abmaj" | "G#M" | "AbM" | "G#/AbM" => {
-                Ok(ChordName::GSharpAFlat(ChordQuality::Maj))
-            }
-            "G#m" | "Abm" | "G#/Abm" | "G#min" | "Abmin" | "G#/Abmin" | "G#-" | "Ab-"
-            | "G#/Ab-" => Ok(ChordName::GSharpAFlat(ChordQuality::Min)),
-            "G#aug" | "G#+" | "Abaug" | "Ab+" | "G#/Ab+" | "G#/Abaug" => {
-                Ok(ChordName::GSharpAFlat(ChordQuality::Aug))
-            }
-            "G#dim" | "G#Ø" | "G#°" | "Abdim" | "AbØ" | "Ab°" | "G#/Abdim" | "G#/AbØ"
-            | "G#/Ab°" => Ok(ChordName::GSharpAFlat(ChordQuality::Dim)),
+    // slash symbol with letter-based root and bass (ex: `Ebmaj7/G` rather than `D#/Ebmaj7/G`)
+    pub fn spelled_symbol(&self, key: Option<&Key>) -> String {
+        if self.is_root_position() {
+            return self.chord.spelled_symbol(key);
+        }
 
-            "G#sus2" | "G#s2" | "G#/Absus2" | "G#/Abs2" | "Absus2" | "Abs2" => {
-                Ok(ChordName::GSharpAFlat(ChordQuality::Sus2))
-            }
-            "G#sus4" | "G#sus" | "G#s4" | "G#/Absus4" | "G#/Absus" | "G#/Abs4" | "Absus4"
-            | "Abs4" | "Absus" => Ok(ChordName::GSharpAFlat(ChordQuality::Sus4)),
-            "G#maj7" | "G#M7" | "G#^7" | "G#/Abmaj7" | "G#/AbM7" | "G#/Ab^7" | "Abmaj7"
-            | "AbM7" | "Ab^7" => Ok(ChordName::GSharpAFlat(ChordQuality::MajSev)),
-            "G#min7" | "G#m7" | "G#-7" | "G#/Abmin7" | "G#/Abm7" | "G#/Ab-7" | "Abmin7"
-            | "Abm7" | "Ab-7" => Ok(ChordName::GSharpAFlat(ChordQuality::MinSev)),
-            "G#7" | "G#dom7" | "G#/Ab7" | "G#/Abdom7" | "Ab7" | "Abdom7" => {
-                Ok(ChordName::GSharpAFlat(ChordQuality::Sev))
-            }
-            "G#7sus4" | "G#7s4" | "G#7s11" | "G#11" | "G#sus11" | "G#/Ab7sus4" | "G#/Ab7s4"
-            | "G#/Ab7s11" | "G#/Ab11" | "G#/Absus11" | "Ab7sus4" | "Ab7s4" | "Ab7s11" | "Ab11"
-            | "Absus11" => Ok(ChordName::GSharpAFlat(ChordQuality::SevSus)),
-
-            "A" | "Amaj" | "AM" => Ok(ChordName::A(ChordQuality::Maj)),
-            "Am" | "Amin" | "A-" => Ok(ChordName::A(ChordQuality::Min)),
-            "Aaug" | "A+" => Ok(ChordName::A(ChordQuality::Aug)),
-            "Adim" | "AØ" | "A°" => Ok(ChordName::A(ChordQuality::Dim)),
-
-            "Asus2" | "As2" => Ok(ChordName::A(ChordQuality::Sus2)),
-            "Asus4" | "Asus" | "As4" => Ok(ChordName::A(ChordQuality::Sus4)),
-            "Amaj7" | "AM7" | "A^7" => Ok(ChordName::A(ChordQuality::MajSev)),
-            "Amin7" | "Am7" | "A-7" => Ok(ChordName::A(ChordQuality::MinSev)),
-            "A7" | "Adom7" => Ok(ChordName::A(ChordQuality::Sev)),
-            "A7sus4" | "A7s4" | "A7s11" | "A11" | "Asus11" => {
-                Ok(ChordName::A(ChordQuality::SevSus))
-            }
+        let root = self.chord.spelled_root(key);
+        format!(
+            "{}{}/{}",
+            root,
+            self.chord.get_quality(),
+            self.spelled_bass(&root)
+        )
+    }
 
-            "A#" | "Bb" | "A#maj" | "Bbmaj" | "A#/Bbmaj" | "A#M" | "BbM" | "A#/BbM" => {
-                Ok(ChordName::ASharpBFlat(ChordQuality::Maj))
-            }
-            "A#m" | "Bbm" | "A#/Bbm" | "A#min" | "Bbmin" | "A#/Bbmin" | "A#-" | "Bb-"
-            | "A#/Bb-" => Ok(ChordName::ASharpBFlat(ChordQuality::Min)),
-            "A#aug" | "A#+" | "Bbaug" | "Bb+" | "A#/Bb+" | "A#/Bbaug" => {
-                Ok(ChordName::ASharpBFlat(ChordQuality::Aug))
-            }
-            "A#dim" | "A#Ø" | "A#°" | "Bbdim" | "BbØ" | "Bb°" | "A#/Bbdim" | "A#/BbØ"
-            | "A#/Bb°" => Ok(ChordName::ASharpBFlat(ChordQuality::Dim)),
+    // bass letter counted from the spelled `root` (ex: `G` in `Ebmaj7/G`, `B#` in `G#7/B#`)
+    pub(crate) fn spelled_bass(&self, root: &SpelledNote) -> SpelledNote {
+        let semitones = (self.bass.pitch_class() + 12 - self.chord.get_root().pitch_class()) % 12;
 
-            "A#sus2" | "A#s2" | "A#/Bbsus2" | "A#/Bbs2" | "Bbsus2" | "Bbs2" => {
-                Ok(ChordName::ASharpBFlat(ChordQuality::Sus2))
-            }
-            "A#sus4" | "A#sus" | "A#s4" | "A#/Bbsus4" | "A#/Bbsus" | "A#/Bbs4" | "Bbsus4"
-            | "Bbs4" | "Bbsus" => Ok(ChordName::ASharpBFlat(ChordQuality::Sus4)),
-            "A#maj7" | "A#M7" | "A#^7" | "A#/Bbmaj7" | "A#/BbM7" | "A#/Bb^7" | "Bbmaj7"
-            | "BbM7" | "Bb^7" => Ok(ChordName::ASharpBFlat(ChordQuality::MajSev)),
-            "A#min7" | "A#m7" | "A#-7" | "A#/Bbmin7" | "A#/Bbm7" | "A#/Bb-7" | "Bbmin7"
-            | "Bbm7" | "Bb-7" => Ok(ChordName::ASharpBFlat(ChordQuality::MinSev)),
-            "A#7" | "A#dom7" | "A#/Bb7" | "A#/Bbdom7" | "Bb7" | "Bbdom7" => {
-                Ok(ChordName::ASharpBFlat(ChordQuality::Sev))
-            }
-            "A#7sus4" | "A#7s4" | "A#7s11" | "A#11" | "A#sus11" | "A#/Bb7sus4" | "A#/Bb7s4"
-            | "A#/Bb7s11" | "A#/Bb11" | "A#/Bbsus11" | "Bb7sus4" | "Bb7s4" | "Bb7s11" | "Bb11"
-            | "Bbsus11" => Ok(ChordName::ASharpBFlat(ChordQuality::SevSus)),
-
-            "B" | "Bmaj" | "BM" => Ok(ChordName::B(ChordQuality::Maj)),
-            "Bm" | "Bmin" | "B-" => Ok(ChordName::B(ChordQuality::Min)),
-            "Baug" | "B+" => Ok(ChordName::B(ChordQuality::Aug)),
-            "Bdim" | "BØ" | "B°" => Ok(ChordName::B(ChordQuality::Dim)),
-
-            "Bsus2" | "Bs2" => Ok(ChordName::B(ChordQuality::Sus2)),
-            "Bsus4" | "Bsus" | "Bs4" => Ok(ChordName::B(ChordQuality::Sus4)),
-            "Bmaj7" | "BM7" | "B^7" => Ok(ChordName::B(ChordQuality::MajSev)),
-            "Bmin7" | "Bm7" | "B-7" => Ok(ChordName::B(ChordQuality::MinSev)),
-            "B7" | "Bdom7" => Ok(ChordName::B(ChordQuality::Sev)),
-            "B7sus4" | "B7s4" | "B7s11" | "B11" | "Bsus11" => {
-                Ok(ChordName::B(ChordQuality::SevSus))
-            }
+        self.spelling(SpellingForm::Shell)
+            .and_then(|chord_spelling| {
+                let number = chord_spelling.letter_number(&ChordTone::Bass(self.bass), semitones);
+                root.above(number, semitones)
+            })
+            .unwrap_or_else(|| SpelledNote::from(self.bass))
+    }
+}
+
+impl From<ChordName> for SlashChord {
+    fn from(chord: ChordName) -> Self {
+        Self::new(chord, &chord.get_root())
+    }
+}
+
+impl Transpose for SlashChord {
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError> {
+        Ok(SlashChord::new(
+            self.chord.transpose(semitones)?,
+            &self.bass.transpose(semitones)?,
+        ))
+    }
+}
 
-            unknown => Err(ChordNameError(unknown.to_string())),
+impl Display for SlashChord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_root_position() {
+            write!(f, "{}", self.chord)
+        } else {
+            write!(f, "{}/{}", self.chord, self.bass)
         }
     }
 }
 
+impl TryFrom<&str> for SlashChord {
+    type Error = ChordNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (chord, bass) = ChordName::parse_with_bass(value)?;
+
+        Ok(SlashChord::new(chord, &bass.unwrap_or(chord.get_root())))
+    }
+}
+
+// a chord symbol as written, keeping the letters of its root and bass
+// (ex: `Bbm7b5` spells `Bb`, `Db`, `Fb`, `Ab` - its `ChordName` alone only knows the `A#/Bb` pitch)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpelledChord {
+    chord: SlashChord,
+    // `None` for notes written with both names (ex: `C#/Db7`)
+    root: Option<SpelledNote>,
+    bass: Option<SpelledNote>,
+}
+
+impl SpelledChord {
+    // `None` when `root` doesn't name the chord's root (ex: `Db` for a `Cmaj` chord)
+    pub fn new(chord: SlashChord, root: &SpelledNote) -> Option<Self> {
+        (root.note_name() == chord.chord().get_root()).then_some(Self {
+            chord,
+            root: Some(*root),
+            bass: None,
+        })
+    }
+
+    pub fn chord(&self) -> SlashChord {
+        self.chord
+    }
+
+    // the written root, otherwise its spelling in `key` or with the fewest accidentals
+    pub fn root(&self, key: Option<&Key>) -> SpelledNote {
+        self.root
+            .unwrap_or_else(|| self.chord.chord().spelled_root(key))
+    }
+
+    // the written bass, otherwise its letter counted from the root (ex: `Gb` in `Ebm7/Gb`)
+    pub fn bass(&self, key: Option<&Key>) -> SpelledNote {
+        self.bass
+            .unwrap_or_else(|| self.chord.spelled_bass(&self.root(key)))
+    }
+
+    // letter-based chord tones (shell spelling) from the written root
+    // (Note: a written root keeps its letter in any `key`, ex: `Gb7` stays `Gb` in `E` major)
+    pub fn spell(&self, key: Option<&Key>) -> Option<Vec<SpelledNote>> {
+        music_theory_baux
+            .get_chord_spelling(&self.chord.chord())
+            .map(|chord_spelling| chord_spelling.spelled_from(&self.root(key)))
+    }
+
+    // chord symbol with the written letters (ex: `Abm` stays `Abmin` rather than `G#min`)
+    pub fn spelled_symbol(&self, key: Option<&Key>) -> String {
+        let root = self.root(key);
+        let quality = self.chord.chord().get_quality();
+
+        match self.chord.is_root_position() {
+            true => format!("{}{}", root, quality),
+            false => format!("{}{}/{}", root, quality, self.bass(key)),
+        }
+    }
+}
+
+impl Display for SpelledChord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.spelled_symbol(None))
+    }
+}
+
+impl TryFrom<&str> for SpelledChord {
+    type Error = ChordNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let symbol = parse_chord_symbol(value)?;
+        let chord = ChordName::new(&symbol.root, symbol.quality);
+        let bass = symbol.bass.filter(|bass| *bass != symbol.root);
+
+        Ok(Self {
+            chord: SlashChord::new(chord, &bass.unwrap_or(symbol.root)),
+            root: symbol.spelled_root,
+            bass: bass.and(symbol.spelled_bass),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ChordTone {
     Root(NoteName),
@@ -359,20 +857,85 @@ pub enum ChordTone {
     Third(NoteName),
     Fourth(NoteName),
     Fifth(NoteName),
+    Sixth(NoteName),
     Seventh(NoteName),
+    Ninth(NoteName),
+    Eleventh(NoteName),
+    Thirteenth(NoteName),
+    // slash chord bass note (ex: the `E` of `C/E`) - may double one of the chord's own tones
+    Bass(NoteName),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ChordToneDegree {
     Root,
     Second,
     Third,
     Fourth,
     Fifth,
+    Sixth,
     Seventh,
+    Ninth,
+    Eleventh,
+    Thirteenth,
+    Bass,
+}
+
+impl ChordToneDegree {
+    // interval number above the root (decides the tone's letter when spelling)
+    pub fn number(&self) -> usize {
+        match self {
+            ChordToneDegree::Root => 1,
+            ChordToneDegree::Second => 2,
+            ChordToneDegree::Third => 3,
+            ChordToneDegree::Fourth => 4,
+            ChordToneDegree::Fifth => 5,
+            ChordToneDegree::Sixth => 6,
+            ChordToneDegree::Seventh => 7,
+            ChordToneDegree::Ninth => 9,
+            ChordToneDegree::Eleventh => 11,
+            ChordToneDegree::Thirteenth => 13,
+            // Note: bass letters follow their own interval from the root (see `ChordSpelling::spelled_in`)
+            ChordToneDegree::Bass => 1,
+        }
+    }
+}
+
+impl Display for ChordToneDegree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChordToneDegree::Root => write!(f, "Root"),
+            ChordToneDegree::Second => write!(f, "Second"),
+            ChordToneDegree::Third => write!(f, "Third"),
+            ChordToneDegree::Fourth => write!(f, "Fourth"),
+            ChordToneDegree::Fifth => write!(f, "Fifth"),
+            ChordToneDegree::Sixth => write!(f, "Sixth"),
+            ChordToneDegree::Seventh => write!(f, "Seventh"),
+            ChordToneDegree::Ninth => write!(f, "Ninth"),
+            ChordToneDegree::Eleventh => write!(f, "Eleventh"),
+            ChordToneDegree::Thirteenth => write!(f, "Thirteenth"),
+            ChordToneDegree::Bass => write!(f, "Bass"),
+        }
+    }
 }
 
 impl ChordTone {
+    pub fn from_degree(degree: &ChordToneDegree, note: &NoteName) -> Self {
+        match degree {
+            ChordToneDegree::Root => ChordTone::Root(*note),
+            ChordToneDegree::Second => ChordTone::Second(*note),
+            ChordToneDegree::Third => ChordTone::Third(*note),
+            ChordToneDegree::Fourth => ChordTone::Fourth(*note),
+            ChordToneDegree::Fifth => ChordTone::Fifth(*note),
+            ChordToneDegree::Sixth => ChordTone::Sixth(*note),
+            ChordToneDegree::Seventh => ChordTone::Seventh(*note),
+            ChordToneDegree::Ninth => ChordTone::Ninth(*note),
+            ChordToneDegree::Eleventh => ChordTone::Eleventh(*note),
+            ChordToneDegree::Thirteenth => ChordTone::Thirteenth(*note),
+            ChordToneDegree::Bass => ChordTone::Bass(*note),
+        }
+    }
+
     pub fn note(&self) -> &NoteName {
         match self {
             ChordTone::Root(note) => note,
@@ -380,7 +943,12 @@ impl ChordTone {
             ChordTone::Third(note) => note,
             ChordTone::Fourth(note) => note,
             ChordTone::Fifth(note) => note,
+            ChordTone::Sixth(note) => note,
             ChordTone::Seventh(note) => note,
+            ChordTone::Ninth(note) => note,
+            ChordTone::Eleventh(note) => note,
+            ChordTone::Thirteenth(note) => note,
+            ChordTone::Bass(note) => note,
         }
     }
 
@@ -391,20 +959,50 @@ impl ChordTone {
             ChordTone::Third(_) => ChordToneDegree::Third,
             ChordTone::Fourth(_) => ChordToneDegree::Fourth,
             ChordTone::Fifth(_) => ChordToneDegree::Fifth,
+            ChordTone::Sixth(_) => ChordToneDegree::Sixth,
             ChordTone::Seventh(_) => ChordToneDegree::Seventh,
+            ChordTone::Ninth(_) => ChordToneDegree::Ninth,
+            ChordTone::Eleventh(_) => ChordToneDegree::Eleventh,
+            ChordTone::Thirteenth(_) => ChordToneDegree::Thirteenth,
+            ChordTone::Bass(_) => ChordToneDegree::Bass,
         }
     }
 }
 
+impl Transpose for ChordTone {
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError> {
+        Ok(ChordTone::from_degree(
+            &self.get_tone_degree(),
+            &self.note().transpose(semitones)?,
+        ))
+    }
+}
+
 impl Display for ChordTone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.get_tone_degree(), self.note())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Error trying to apply notes `{0}` to `ChordSpelling` (Note: expects one note per chord tone, root first, matching each tone's `NoteName`)")]
+pub struct ChordSpellingError(String);
+
+// which chord tones a `ChordSpelling` holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SpellingForm {
+    // root, third and seventh - no unaltered fifth (ex: `Cmaj7` -> `C`, `E`, `B`)
+    #[default]
+    Shell,
+    // every tone of the chord's formula (ex: `Cmaj7` -> `C`, `E`, `G`, `B`)
+    Complete,
+}
+
+impl Display for SpellingForm {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            ChordTone::Root(note) => write!(f, "Root: {note}"),
-            ChordTone::Second(note) => write!(f, "Second: {note}"),
-            ChordTone::Third(note) => write!(f, "Third: {note}"),
-            ChordTone::Fourth(note) => write!(f, "Fourth: {note}"),
-            ChordTone::Fifth(note) => write!(f, "Fifth: {note}"),
-            ChordTone::Seventh(note) => write!(f, "Seventh: {note}"),
+            SpellingForm::Shell => write!(f, "shell"),
+            SpellingForm::Complete => write!(f, "complete"),
         }
     }
 }
@@ -412,66 +1010,429 @@ impl Display for ChordTone {
 #[derive(Debug, Clone, PartialEq)]
 pub struct ChordSpelling {
     name: ChordName,
+    form: SpellingForm,
     spelling: Vec<ChordTone>,
+    spelled_notes: Vec<SpelledNote>,
 }
 
 impl ChordSpelling {
     // pub fn new(root: &NoteName, quality: &ChordQuality, notes: &Vec<NoteName>) -> Self {
+    // (Note: panics on `notes` that don't fit the shell spelling - see `with_form`)
     pub fn new(root: &NoteName, quality: &ChordQuality, notes: &[NoteName]) -> Self {
-        let name = create_chord_name(root, quality);
+        Self::with_form(root, quality, notes, SpellingForm::Shell)
+            .expect("should have one note per tone of the quality's shell spelling")
+    }
 
-        let mut spelling = Vec::with_capacity(3);
-        spelling.push(ChordTone::Root(*root));
+    // `notes` need one note per tone of the quality's `spelling_formula(form)`, root first
+    // (ex: `Cmaj7` shell -> `C`, `E`, `B`)
+    pub fn with_form(
+        root: &NoteName,
+        quality: &ChordQuality,
+        notes: &[NoteName],
+        form: SpellingForm,
+    ) -> Result<Self, ChordSpellingError> {
+        let name = ChordName::new(root, *quality);
+        let formula = quality.spelling_formula(form);
+        let matches_tones = notes.len() == formula.len()
+            && notes
+                .iter()
+                .zip(formula.iter())
+                .all(|(note, (_, semitones))| {
+                    note.pitch_class() == (root.pitch_class() + semitones) % 12
+                });
 
-        match quality {
-            ChordQuality::Maj | ChordQuality::Min | ChordQuality::Aug | ChordQuality::Dim => {
-                spelling.push(ChordTone::Third(notes[1]));
-                spelling.push(ChordTone::Fifth(notes[2]));
-            }
-            ChordQuality::Sus2 => {
-                spelling.push(ChordTone::Second(notes[1]));
-                spelling.push(ChordTone::Fifth(notes[2]));
-            }
-            ChordQuality::Sus4 => {
-                spelling.push(ChordTone::Fourth(notes[1]));
-                spelling.push(ChordTone::Fifth(notes[2]));
-            }
-            ChordQuality::MajSev | ChordQuality::MinSev | ChordQuality::Sev => {
-                spelling.push(ChordTone::Third(notes[1]));
-                spelling.push(ChordTone::Seventh(notes[2]));
-            }
-            ChordQuality::SevSus => {
-                spelling.push(ChordTone::Fourth(notes[1]));
-                spelling.push(ChordTone::Seventh(notes[2]));
-            }
+        if !matches_tones {
+            let notes = notes
+                .iter()
+                .map(|note| note.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(ChordSpellingError(notes));
         }
 
-        Self { name, spelling }
+        // Note: tones follow `spelling_formula` order - the first one is always the root
+        let spelling = formula
+            .iter()
+            .zip(notes.iter())
+            .map(|((degree, _), note)| ChordTone::from_degree(degree, note))
+            .collect();
+
+        let mut chord_spelling = Self {
+            name,
+            form,
+            spelling,
+            spelled_notes: Vec::new(),
+        };
+        chord_spelling.spelled_notes = chord_spelling.spelled_in(None);
+
+        Ok(chord_spelling)
+    }
+
+    // spelling from chord tones already matched to their degrees (ex: a dictionary entry)
+    pub(crate) fn from_chord_tones(
+        name: ChordName,
+        form: SpellingForm,
+        spelling: Vec<ChordTone>,
+    ) -> Self {
+        let mut chord_spelling = Self {
+            name,
+            form,
+            spelling,
+            spelled_notes: Vec::new(),
+        };
+        chord_spelling.spelled_notes = chord_spelling.spelled_in(None);
+
+        chord_spelling
+    }
+
+    // spelling built from the quality's own formula (ex: `C9` shell -> `C`, `E`, `Bb`, `D`)
+    pub fn from_formula(root: &NoteName, quality: &ChordQuality, form: SpellingForm) -> Self {
+        let notes = quality
+            .spelling_formula(form)
+            .iter()
+            .map(|(_, semitones)| NoteName::from_pitch_class(root.pitch_class() + semitones))
+            .collect::<Vec<_>>();
+
+        Self::with_form(root, quality, &notes, form).expect("should fit the quality's own formula")
+    }
+
+    // letter-based spelling where each tone follows its interval letter from the root
+    // (Note: with a `key`, the root takes the key's spelling - ex: `D#` for the iii chord of `B` major)
+    pub fn spelled_in(&self, key: Option<&Key>) -> Vec<SpelledNote> {
+        let root = self.name.get_root();
+        let spelled_root = match key {
+            Some(key) => key.spell_note(&root),
+            None => fewest_accidentals_root(&root, &self.degrees()),
+        };
+
+        spell_degrees(spelled_root, &self.degrees())
+    }
+
+    // letter-based spelling from a root letter already decided on (ex: a written `Bb` root)
+    // (Note: `root` should name the chord's own root - any other note falls back to `spelled_in`)
+    pub fn spelled_from(&self, root: &SpelledNote) -> Vec<SpelledNote> {
+        if root.note_name() != self.name.get_root() {
+            return self.spelled_in(None);
+        }
+
+        spell_degrees(*root, &self.degrees())
+    }
+
+    // `(letter number, semitones above the root)` of each tone
+    fn degrees(&self) -> Vec<(usize, usize)> {
+        let root = self.name.get_root();
+
+        self.spelling
+            .iter()
+            .map(|chord_tone| {
+                let semitones = (chord_tone.note().pitch_class() + 12 - root.pitch_class()) % 12;
+                (self.letter_number(chord_tone, semitones), semitones)
+            })
+            .collect()
+    }
+
+    // a bass doubling a chord tone takes that tone's letter (ex: `E` in `C/E`), any other
+    // bass follows its common interval name from the root (ex: `C/F#` -> `A4` -> `F#`)
+    fn letter_number(&self, chord_tone: &ChordTone, semitones: usize) -> usize {
+        if chord_tone.get_tone_degree() != ChordToneDegree::Bass {
+            return chord_tone.get_tone_degree().number();
+        }
+
+        self.spelling
+            .iter()
+            .find(|other| {
+                other.get_tone_degree() != ChordToneDegree::Bass
+                    && other.note() == chord_tone.note()
+            })
+            .map(|other| other.get_tone_degree().number())
+            .unwrap_or_else(|| Interval::from_semitones(semitones).number())
+    }
+
+    // same spelling over a slash `bass` note, added after the chord's own tones
+    // (ex: `Cmaj` + `E` -> `C`, `E`, `G`, bass `E`) - a bass matching the root changes nothing
+    pub fn with_bass(mut self, bass: &NoteName) -> Self {
+        self.spelling
+            .retain(|chord_tone| chord_tone.get_tone_degree() != ChordToneDegree::Bass);
+        if *bass != self.name.get_root() {
+            self.spelling.push(ChordTone::Bass(*bass));
+        }
+        self.spelled_notes = self.spelled_in(None);
+
+        self
+    }
+
+    pub fn bass(&self) -> Option<&NoteName> {
+        self.spelling
+            .iter()
+            .find(|chord_tone| chord_tone.get_tone_degree() == ChordToneDegree::Bass)
+            .map(ChordTone::note)
+    }
+
+    // swap in letter-specific spellings (ex: `D#` over the default `Eb` for a `D#/Eb` tone)
+    pub fn with_spelled_notes(
+        mut self,
+        spelled_notes: &[SpelledNote],
+    ) -> Result<Self, ChordSpellingError> {
+        let matches_tones = spelled_notes.len() == self.spelling.len()
+            && spelled_notes
+                .iter()
+                .zip(self.spelling.iter())
+                .all(|(spelled, chord_tone)| spelled.note_name() == *chord_tone.note());
+
+        if !matches_tones {
+            let notes = spelled_notes
+                .iter()
+                .map(|spelled| spelled.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(ChordSpellingError(notes));
+        }
+
+        self.spelled_notes = spelled_notes.to_vec();
+        Ok(self)
     }
 
     pub fn name(&self) -> &ChordName {
         &self.name
     }
 
+    pub fn form(&self) -> SpellingForm {
+        self.form
+    }
+
     pub fn spelling(&self) -> &Vec<ChordTone> {
         &self.spelling
     }
+
+    pub fn spelled_notes(&self) -> &Vec<SpelledNote> {
+        &self.spelled_notes
+    }
+
+    pub fn get_spelled_note(&self, note: &NoteName) -> Option<&SpelledNote> {
+        self.spelled_notes
+            .iter()
+            .find(|spelled| spelled.note_name() == *note)
+    }
+
+    // `transposed` with this spelling's letters moved along, keeping its own spelling when
+    // a moved tone would need more than a double accidental
+    fn carry_spellings(
+        &self,
+        transposed: Self,
+        move_note: impl Fn(&SpelledNote) -> Result<SpelledNote, TransposeError>,
+    ) -> Self {
+        match self.spelled_notes.iter().map(move_note).collect() {
+            Ok(spelled_notes) => Self {
+                spelled_notes,
+                ..transposed
+            },
+            Err(_) => transposed,
+        }
+    }
+}
+
+// Note: semitone moves respell from the new root, interval moves carry each tone's letter
+// along (ex: `D#/Ebmaj7` spelled `D#`, `F##`, `C##` up a `m2` -> `E`, `G#`, `D#`)
+impl Transpose for ChordSpelling {
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError> {
+        let mut transposed = Self {
+            name: self.name.transpose(semitones)?,
+            form: self.form,
+            spelling: self
+                .spelling
+                .iter()
+                .map(|chord_tone| chord_tone.transpose(semitones))
+                .collect::<Result<Vec<_>, _>>()?,
+            spelled_notes: Vec::new(),
+        };
+        transposed.spelled_notes = transposed.spelled_in(None);
+
+        Ok(transposed)
+    }
+
+    fn transpose_up(&self, interval: &Interval) -> Result<Self, TransposeError> {
+        let transposed = self.transpose(interval.semitones() as i32)?;
+
+        Ok(self.carry_spellings(transposed, |spelled| spelled.transpose_up(interval)))
+    }
+
+    fn transpose_down(&self, interval: &Interval) -> Result<Self, TransposeError> {
+        let transposed = self.transpose(-(interval.semitones() as i32))?;
+
+        Ok(self.carry_spellings(transposed, |spelled| spelled.transpose_down(interval)))
+    }
 }
 
 impl Display for ChordSpelling {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Spelling for {}:\n{} -- {} -- {}\n",
-            self.name, self.spelling[0], self.spelling[1], self.spelling[2]
-        )
+        let tones = self
+            .spelling
+            .iter()
+            .zip(self.spelled_notes.iter())
+            .map(|(chord_tone, spelled)| format!("{}: {spelled}", chord_tone.get_tone_degree()))
+            .collect::<Vec<_>>()
+            .join(" -- ");
+
+        writeln!(f, "Spelling for {}:\n{}", self.name, tones)
     }
 }
 
-fn create_chord_name(root: &NoteName, quality: &ChordQuality) -> ChordName {
-    let name = root.to_string() + &quality.to_string();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{music_theory_baux, Letter};
+
+    #[test]
+    fn every_quality_reads_back_from_its_symbol() {
+        for quality in ChordQuality::all() {
+            let symbol = format!("C{}", quality);
+            let parsed = ChordName::try_from(symbol.as_str())
+                .unwrap_or_else(|err| panic!("`{}` should parse: {}", symbol, err));
+
+            assert_eq!(parsed.get_quality(), quality, "`{}`", symbol);
+        }
+    }
+
+    #[test]
+    fn every_formula_is_rooted_and_distinct() {
+        for quality in ChordQuality::all() {
+            let formula = quality.formula();
+            let mut semitones = formula
+                .iter()
+                .map(|(_, semitones)| semitones % 12)
+                .collect::<Vec<_>>();
+
+            assert_eq!(formula[0], (ChordToneDegree::Root, 0), "`{}`", quality);
+            semitones.sort();
+            semitones.dedup();
+            assert_eq!(
+                semitones.len(),
+                formula.len(),
+                "`{}` repeats a tone",
+                quality
+            );
+            assert!(
+                quality
+                    .spelling_formula(SpellingForm::Shell)
+                    .iter()
+                    .all(|tone| formula.contains(tone)),
+                "`{}` shell should be part of its formula",
+                quality
+            );
+        }
+    }
+
+    #[test]
+    fn every_tone_takes_the_letter_of_its_degree() {
+        for quality in ChordQuality::all() {
+            let spelling =
+                ChordSpelling::from_formula(&NoteName::C, &quality, SpellingForm::Complete);
+
+            for (chord_tone, note) in spelling.spelling().iter().zip(spelling.spelled_notes()) {
+                let degree = chord_tone.get_tone_degree().number();
+
+                assert_eq!(
+                    note.letter(),
+                    Letter::C.offset(degree - 1),
+                    "`C{}` spells its {} as `{}`",
+                    quality,
+                    chord_tone.get_tone_degree(),
+                    note
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn extended_chords_spell_by_letter() {
+        let complete = |symbol: &str| {
+            let chord_name = ChordName::try_from(symbol).unwrap();
+
+            music_theory_baux
+                .get_chord_spelling_form(&chord_name, SpellingForm::Complete)
+                .unwrap()
+                .spelled_notes()
+                .iter()
+                .map(|note| note.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        assert_eq!(complete("Dm9"), "D F A C E");
+        assert_eq!(complete("G13"), "G B D F A E");
+        assert_eq!(complete("F6/9"), "F A C D G");
+        // flat roots keep flat tensions
+        assert_eq!(complete("Bbm11"), "Bb Db F Ab C Eb");
+        // raised tensions are sharps of their own letter, lowered ones flats
+        assert_eq!(complete("G7b9#5"), "G B D# F Ab");
+        assert_eq!(complete("Cmaj7#11"), "C E G B F#");
+        assert_eq!(complete("C7b9sus4"), "C F G Bb Db");
+        assert_eq!(complete("E7alt"), "E G# D F F## A# C");
+    }
+
+    fn spelled(symbol: &str, form: SpellingForm) -> String {
+        let chord = ChordName::try_from(symbol).unwrap();
+        let spelling = ChordSpelling::from_formula(&chord.get_root(), &chord.get_quality(), form);
+
+        spelling
+            .spelled_notes()
+            .iter()
+            .map(|note| note.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn shells_leave_out_the_fifth_of_seventh_chords() {
+        use SpellingForm::{Complete, Shell};
 
-    ChordName::try_from(name.as_str()).expect(
-        "`create_chord_name` should create valid `ChordName` with given `root` + `quality` parts",
-    )
+        // triads and sixths keep every tone
+        assert_eq!(spelled("Cm", Shell), spelled("Cm", Complete));
+        assert_eq!(spelled("Csus4", Shell), "C F G");
+        assert_eq!(spelled("C6", Shell), "C E G A");
+
+        assert_eq!(spelled("Cmaj7", Shell), "C E B");
+        assert_eq!(spelled("Cmaj7", Complete), "C E G B");
+        assert_eq!(spelled("Dm7b5", Shell), "D F Ab C");
+        assert_eq!(spelled("C°7", Shell), "C Eb Gb Bbb");
+        assert_eq!(spelled("Cm(maj7)", Complete), "C Eb G B");
+        assert_eq!(spelled("C+7", Shell), "C E G# Bb");
+        assert_eq!(spelled("C7sus4", Shell), "C F Bb");
+        assert_eq!(spelled("C9sus4", Complete), "C F G Bb D");
+        assert_eq!(spelled("C11", Shell), "C Bb D F");
+        assert_eq!(spelled("C11", Complete), "C E G Bb D F");
+        assert_eq!(spelled("Cm13", Shell), "C Eb Bb D F A");
+
+        // altered fifths stay in the shell, `alt` has no natural fifth in either form
+        assert_eq!(spelled("C7#5b9", Shell), "C E G# Bb Db");
+        assert_eq!(spelled("C7alt", Shell), spelled("C7alt", Complete));
+        assert_eq!(spelled("C7alt", Complete), "C E Bb Db D# F# Ab");
+    }
+
+    #[test]
+    fn spellings_need_one_note_per_tone() {
+        use NoteName::*;
+
+        let shell =
+            ChordSpelling::with_form(&C, &ChordQuality::MajSev, &[C, E, B], SpellingForm::Shell)
+                .unwrap();
+        assert_eq!(
+            shell,
+            ChordSpelling::new(&C, &ChordQuality::MajSev, &[C, E, B])
+        );
+        assert_eq!(shell.spelling()[2], ChordTone::Seventh(B));
+
+        for notes in [&[C, E][..], &[C, E, G, B], &[C, E, ASharpBFlat], &[D, E, B]] {
+            let result =
+                ChordSpelling::with_form(&C, &ChordQuality::MajSev, notes, SpellingForm::Shell);
+            assert!(result.is_err(), "{:?}", notes);
+        }
+        assert!(ChordSpelling::with_form(
+            &C,
+            &ChordQuality::MajSev,
+            &[C, E, G, B],
+            SpellingForm::Complete
+        )
+        .is_ok());
+    }
 }
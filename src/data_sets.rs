@@ -1,111 +1,53 @@
-use crate::{ChordName, ChordSpelling, NoteName, NoteOct, ScaleName, ScaleSpelling};
+use crate::{
+    ChordName, ChordQuality, ChordSpelling, NoteName, NoteOct, SpellingForm, Tuning, CONCERT_PITCH,
+};
 use std::collections::HashMap;
 
-static NOTE_DATA: &str = include_str!(".././note_frequencies.txt");
-static CHORD_DATA: &str = include_str!(".././chord_spellings.txt");
-static SCALE_DATA: &str = include_str!(".././major_minor_scales.txt");
-
 // === NOTES/FREQUENCIES || NOTE WEIGHTS === //
 
-// todo(?): refactor to an encompassing 'type' (re: clippy)
-// #[derive(Debug, Clone)]
-// pub struct NoteFreqData {
-//     note_freqs: HashMap<NoteOct, f64>,
-//     freq_notes: HashMap<&'static str, NoteOct>,
-//     note_freq_collections: HashMap<NoteName, Vec<f64>>,
-//     note_weights: HashMap<NoteOct, usize>,
-//     weight_notes: HashMap<usize, NoteOct>,
-// }
+// `note_freqs`, `freq_notes`, `note_freq_collections`, `note_weights` and `weight_notes` collections
+#[derive(Debug, Clone)]
+struct NoteFreqData {
+    note_freqs: HashMap<NoteOct, f64>,
+    freq_notes: HashMap<String, NoteOct>,
+    note_freq_collections: HashMap<NoteName, Vec<f64>>,
+    note_weights: HashMap<NoteOct, usize>,
+    weight_notes: HashMap<usize, NoteOct>,
+}
 
 // `note_freqs`, `freq_notes`, `note_freq_collections`, and `note_weights` fields for `MusicTheoryBaux`
-fn generate_notes_freqs_data() -> (
-    HashMap<NoteOct, f64>,
-    HashMap<&'static str, NoteOct>,
-    HashMap<NoteName, Vec<f64>>,
-    HashMap<NoteOct, usize>,
-    HashMap<usize, NoteOct>,
-) {
-    // todo(?): refactor to an encompassing 'type' (re: clippy)
-    // fn generate_notes_freqs_data() -> NoteFreqData {
+fn generate_notes_freqs_data(reference_pitch: f64, tuning: &Tuning) -> NoteFreqData {
     let mut note_freqs = HashMap::<NoteOct, f64>::new();
-    let mut freq_notes = HashMap::<&'static str, NoteOct>::new();
-    let mut note_freq_collections = HashMap::<NoteName, Vec<f64>>::from([
-        (NoteName::A, vec![]),
-        (NoteName::ASharpBFlat, vec![]),
-        (NoteName::B, vec![]),
-        (NoteName::C, vec![]),
-        (NoteName::CSharpDFlat, vec![]),
-        (NoteName::D, vec![]),
-        (NoteName::DSharpEFlat, vec![]),
-        (NoteName::E, vec![]),
-        (NoteName::F, vec![]),
-        (NoteName::FSharpGFlat, vec![]),
-        (NoteName::G, vec![]),
-        (NoteName::GSharpAFlat, vec![]),
-    ]);
+    let mut freq_notes = HashMap::<String, NoteOct>::new();
+    let mut note_freq_collections = HashMap::<NoteName, Vec<f64>>::new();
     let mut note_weights = HashMap::<NoteOct, usize>::new();
     let mut weight_notes = HashMap::<usize, NoteOct>::new();
-    let mut weight = 0;
-
-    for line in NOTE_DATA.lines() {
-        if let Some((note_name_str, freq_str)) = line.split_once(" = ") {
-            let freq_str = freq_str.trim();
-            let freq = freq_str
-                .parse::<f64>()
-                .expect("ERROR: `generate_note_freqs` parsing issue in initial data read of `freq` line value - note_frequencies.txt");
-            let note_name_parts = note_name_str
-                .trim()
-                .split(&['[', ']'])
-                .collect::<Vec<&str>>();
-
-            // expect a collection shape similar to `["noteFreq", "1", "", "\"C\"", ""]`
-            if note_name_parts.len() < 5 {
-                panic!("ERROR: `generate_note_freqs` parsing issue in iniital data read of `note_name_parts` line value - note_frequencies.txt")
-            } else {
-                let note = note_name_parts[3].replace('"', "");
-                let octave = note_name_parts[1];
-
-                let note_name = NoteName::try_from(note.as_str()).unwrap();
-
-                // add current frequency to `NoteName` collection
-                let note_name_freqs = note_freq_collections
-                    .get_mut(&note_name)
-                    .expect("`note_freqs_collection` should have all `NoteName` values");
-                note_name_freqs.push(freq);
-
-                // format and add `NoteOct` freq value to collection
-                let note_oct = format!("{}{}", note, octave);
-                let note_oct = NoteOct::try_from(note_oct.as_str()).expect("ERROR: malformed data in parsing `NoteOct` from `note_name` (in `generate_note_freqs`");
-
-                note_freqs.insert(note_oct, freq);
-
-                // add `freq` (str)'s `NoteOct` value to collection
-                freq_notes.insert(freq_str, note_oct);
-
-                // add `NoteOct` weight value  (`C1 = 0, C#/Db1 = 1, D1 = 2...`)
-                note_weights.insert(note_oct, weight);
-                // add weight value's `NoteOct`
-                weight_notes.insert(weight, note_oct);
-                // increment `weight` for next input line's value
-                weight += 1;
-            }
+
+    for oct in NoteOct::OCTAVES {
+        for pitch_class in 0..12 {
+            let note_oct = NoteOct::from_note(&NoteName::from_pitch_class(pitch_class), oct);
+            // `weight` is the note's semitone distance from `C-2` (`C-2 = 0, C#/Db-2 = 1, D-2 = 2...`)
+            let weight = note_oct.semitone_index().expect("should be within OCTAVES");
+            let freq = tuning.frequency(&note_oct, reference_pitch);
+
+            note_freqs.insert(note_oct, freq);
+            freq_notes.insert(freq.to_string(), note_oct);
+            note_freq_collections
+                .entry(note_oct.note_name())
+                .or_default()
+                .push(freq);
+            note_weights.insert(note_oct, weight);
+            weight_notes.insert(weight, note_oct);
         }
     }
 
-    // NoteFreqData {
-    //     note_freqs,
-    //     freq_notes,
-    //     note_freq_collections,
-    //     note_weights,
-    //     weight_notes,
-    // }
-    (
+    NoteFreqData {
         note_freqs,
         freq_notes,
         note_freq_collections,
         note_weights,
         weight_notes,
-    )
+    }
 }
 
 // === ENHARMONICS === //
@@ -144,34 +86,34 @@ fn generate_enharmonics() -> HashMap<NoteName, NoteName> {
 
 // === CHORD SPELLINGS === //
 
+// every root + quality spelled from its interval formula (see `ChordQuality::formula`)
 fn generate_chord_spellings() -> HashMap<ChordName, ChordSpelling> {
-    let mut chord_spellings: HashMap<ChordName, ChordSpelling> = HashMap::new();
-
-    for line in CHORD_DATA.lines() {
-        if let Some((name, notes_str)) = line.split_once(": ") {
-            let notes: Vec<&str> = notes_str
-                .trim()
-                .trim_start_matches('[')
-                .trim_end_matches("],")
-                .trim()
-                .split(", ")
-                .collect();
-
-            let typed_name: ChordName = name.try_into().unwrap();
-            let typed_notes: Vec<NoteName> =
-                notes.iter().map(|n| n.trim().try_into().unwrap()).collect();
-
-            let chord_spelling = ChordSpelling::new(
-                &typed_name.get_root(),
-                &typed_name.get_quality(),
-                &typed_notes,
-            );
-
-            chord_spellings.insert(typed_name, chord_spelling);
-        }
-    }
+    (0..12)
+        .map(NoteName::from_pitch_class)
+        .flat_map(|root| {
+            ChordQuality::all().into_iter().map(move |quality| {
+                let spelling = ChordSpelling::from_formula(&root, &quality, SpellingForm::Shell);
+                (ChordName::new(&root, quality), spelling)
+            })
+        })
+        .collect()
+}
 
+// complete (fifth included) counterpart of every shell spelling (ex: `Cmaj7` -> `C`, `E`, `G`, `B`)
+pub(crate) fn generate_complete_chord_spellings(
+    chord_spellings: &HashMap<ChordName, ChordSpelling>,
+) -> HashMap<ChordName, ChordSpelling> {
     chord_spellings
+        .keys()
+        .map(|name| {
+            let spelling = ChordSpelling::from_formula(
+                &name.get_root(),
+                &name.get_quality(),
+                SpellingForm::Complete,
+            );
+            (*name, spelling)
+        })
+        .collect()
 }
 
 //
@@ -180,18 +122,32 @@ fn generate_chord_spellings() -> HashMap<ChordName, ChordSpelling> {
 
 // === `RUN IT!` === //
 
-pub fn generate_music_data() -> (
+// `note_freqs`, `freq_notes`, `note_freq_collections`, `note_weights`, `weight_notes`,
+// `chord_spellings`, `enharmonics` and `scale_relatives` (major -> minor roots)
+pub type MusicData = (
     HashMap<NoteOct, f64>,
-    HashMap<&'static str, NoteOct>,
+    HashMap<String, NoteOct>,
     HashMap<NoteName, Vec<f64>>,
     HashMap<NoteOct, usize>,
     HashMap<usize, NoteOct>,
     HashMap<ChordName, ChordSpelling>,
     HashMap<NoteName, NoteName>,
     HashMap<NoteName, NoteName>,
-) {
-    let (note_freqs, freq_notes, note_freq_collections, note_weights, weight_notes) =
-        generate_notes_freqs_data();
+);
+
+pub fn generate_music_data() -> MusicData {
+    generate_music_data_for(CONCERT_PITCH, &Tuning::EqualTemperament)
+}
+
+// same as `generate_music_data` with frequencies from `tuning` around `A4 = reference_pitch`
+pub(crate) fn generate_music_data_for(reference_pitch: f64, tuning: &Tuning) -> MusicData {
+    let NoteFreqData {
+        note_freqs,
+        freq_notes,
+        note_freq_collections,
+        note_weights,
+        weight_notes,
+    } = generate_notes_freqs_data(reference_pitch, tuning);
     let chord_spellings = generate_chord_spellings();
     let enharmonics = generate_enharmonics();
 
@@ -213,14 +169,6 @@ pub fn generate_music_data() -> (
 //
 //
 
-// === SCALE SPELLING === //
-fn _generate_scale_spellings() -> HashMap<ScaleName, ScaleSpelling> {
-    todo!();
-    // let mut scale_spellings: HashMap<ScaleName, ScaleSpelling> = HashMap::new();
-
-    // for line in SCALE_DATA.lines() {}
-}
-
 // === RELATIVE KEYS (MAJOR-MINOR) === //
 
 // Note: "major -> minor" scale root note kv's
@@ -253,3 +201,46 @@ fn generate_relative_keys() -> HashMap<NoteName, NoteName> {
         acc
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // hand-typed shell spellings - kept to check the formula-built dictionary against
+    static CHORD_DATA: &str = include_str!(".././chord_spellings.txt");
+
+    #[test]
+    fn chord_spellings_match_table() {
+        let chord_spellings = generate_chord_spellings();
+
+        for (index, line) in CHORD_DATA.lines().enumerate() {
+            let Some((name, notes_str)) = line.split_once(": ") else {
+                continue;
+            };
+            let typed_name: ChordName = name.try_into().unwrap();
+            let typed_notes = notes_str
+                .trim()
+                .trim_start_matches('[')
+                .trim_end_matches("],")
+                .split(", ")
+                .map(|note| NoteName::try_from(note.trim()).unwrap())
+                .collect::<Vec<_>>();
+
+            let computed_notes = chord_spellings
+                .get(&typed_name)
+                .unwrap_or_else(|| panic!("line {}: no spelling for `{}`", index + 1, name))
+                .spelling()
+                .iter()
+                .map(|chord_tone| *chord_tone.note())
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                computed_notes,
+                typed_notes,
+                "line {}: `{}`",
+                index + 1,
+                line
+            );
+        }
+    }
+}
@@ -0,0 +1,498 @@
+use crate::{
+    ChordName, ChordSpelling, ChordTone, NoteName, ScaleName, ScaleSpelling, SlashChord,
+    SpelledNote, SpellingForm,
+};
+use std::collections::HashSet;
+use std::path::Path;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DictionaryError {
+    #[error("ERROR: failed to read dictionary file `{0}` ({1})")]
+    Io(String, std::io::Error),
+    #[error("ERROR: invalid dictionary entry `{line}`: `{entry}` - {reason} (Note: chord entries look like `Cmaj7: [C, E, G, B]` or `house name = Cmaj7/E`, scale entries like `\"C Maj\" = C,D,E,F,G,A,B`)")]
+    Entry {
+        line: usize,
+        entry: String,
+        reason: String,
+    },
+}
+
+impl DictionaryError {
+    fn entry(line: usize, entry: &str, reason: String) -> Self {
+        DictionaryError::Entry {
+            line,
+            entry: entry.to_string(),
+            reason,
+        }
+    }
+}
+
+// chord spellings + named chords for `MusicTheoryBauxBuilder::chord_dictionary`
+// (Note: entries replace the built-in spelling of the same chord and form - they spell the
+// existing `ChordQuality`s rather than add new ones, and only reach the baux they are built into)
+#[derive(Debug, Clone, Default)]
+pub struct ChordDictionary {
+    spellings: Vec<ChordSpelling>,
+    aliases: Vec<(String, SlashChord)>,
+    // in-memory entries are numbered like file lines (ex: the third `with_chord` is entry `3`)
+    entries: usize,
+}
+
+impl ChordDictionary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // one entry per line - blank lines and `#` / `//` comments are skipped
+    // (ex: `Cmaj7: [C, E, G, B],` or `So What = Emin11`)
+    // (Note: any line with an `=` is a named chord, so names may hold a `:` - ex: `Set 2: opener = Dm9`)
+    pub fn parse(text: &str) -> Result<Self, DictionaryError> {
+        let mut dictionary = Self::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let entry = line.trim();
+            if is_blank(entry) {
+                continue;
+            }
+
+            if let Some((alias, chord)) = entry.split_once('=') {
+                dictionary.add_alias(index + 1, entry, alias, chord)?;
+            } else if let Some((name, notes)) = entry.split_once(':') {
+                let notes = notes
+                    .trim()
+                    .trim_end_matches(',')
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .split(',')
+                    .map(str::trim)
+                    .collect::<Vec<_>>();
+                dictionary.add_chord(index + 1, entry, name.trim(), &notes)?;
+            } else {
+                return Err(DictionaryError::entry(
+                    index + 1,
+                    entry,
+                    "expected `name: [notes]` or `alias = chord`".to_string(),
+                ));
+            }
+        }
+
+        Ok(dictionary)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, DictionaryError> {
+        Self::parse(&read_dictionary(path.as_ref())?)
+    }
+
+    // in-memory entry (ex: `("Cmaj7", &["C", "E", "G", "B"])`)
+    pub fn with_chord(mut self, name: &str, notes: &[&str]) -> Result<Self, DictionaryError> {
+        let entry = format!("{}: [{}]", name, notes.join(", "));
+        self.add_chord(self.entries + 1, &entry, name, notes)?;
+
+        Ok(self)
+    }
+
+    // in-memory named chord (ex: `("So What", "Emin11")`)
+    pub fn with_alias(mut self, alias: &str, chord: &str) -> Result<Self, DictionaryError> {
+        let entry = format!("{} = {}", alias, chord);
+        self.add_alias(self.entries + 1, &entry, alias, chord)?;
+
+        Ok(self)
+    }
+
+    pub fn spellings(&self) -> &Vec<ChordSpelling> {
+        &self.spellings
+    }
+
+    pub fn aliases(&self) -> &Vec<(String, SlashChord)> {
+        &self.aliases
+    }
+
+    // notes must be tones of the chord's complete formula, root first - all of them make a
+    // `SpellingForm::Complete` entry, fewer a `SpellingForm::Shell` one
+    fn add_chord(
+        &mut self,
+        line: usize,
+        entry: &str,
+        name: &str,
+        notes: &[&str],
+    ) -> Result<(), DictionaryError> {
+        self.entries = line;
+
+        let chord_name = ChordName::try_from(name).map_err(|err| {
+            DictionaryError::entry(
+                line,
+                entry,
+                format!("`{}` is not a chord name ({})", name, err.reason()),
+            )
+        })?;
+        let root = chord_name.get_root();
+        let formula = chord_name.get_quality().formula();
+
+        let mut tones = Vec::with_capacity(notes.len());
+        let mut seen = HashSet::new();
+        for token in notes {
+            let (note, spelled) = parse_note(token)
+                .ok_or_else(|| DictionaryError::entry(line, entry, not_a_note(token)))?;
+
+            if !seen.insert(note) {
+                return Err(DictionaryError::entry(
+                    line,
+                    entry,
+                    format!("`{}` appears more than once", token),
+                ));
+            }
+
+            let position = formula
+                .iter()
+                .position(|(_, semitones)| {
+                    (root.pitch_class() + semitones) % 12 == note.pitch_class()
+                })
+                .ok_or_else(|| {
+                    DictionaryError::entry(
+                        line,
+                        entry,
+                        format!("`{}` is not a tone of `{}`", token, chord_name),
+                    )
+                })?;
+            tones.push((
+                position,
+                ChordTone::from_degree(&formula[position].0, &note),
+                spelled,
+            ));
+        }
+
+        if tones.first().map(|(position, ..)| *position) != Some(0) {
+            return Err(DictionaryError::entry(
+                line,
+                entry,
+                format!("the first note should be the root `{}`", root),
+            ));
+        }
+
+        // tones follow formula order, like the built-in spellings
+        tones.sort_by_key(|(position, ..)| *position);
+        let form = match tones.len() == formula.len() {
+            true => SpellingForm::Complete,
+            false => SpellingForm::Shell,
+        };
+        let chord_spelling = ChordSpelling::from_chord_tones(
+            chord_name,
+            form,
+            tones.iter().map(|(_, chord_tone, _)| *chord_tone).collect(),
+        );
+
+        // letter-specific notes (ex: `D#` rather than `D#/Eb`) keep the entry's own spelling
+        let spelled_notes = tones
+            .iter()
+            .zip(chord_spelling.spelled_notes())
+            .map(|((_, _, spelled), computed)| spelled.unwrap_or(*computed))
+            .collect::<Vec<_>>();
+        let chord_spelling = chord_spelling
+            .with_spelled_notes(&spelled_notes)
+            .map_err(|err| DictionaryError::entry(line, entry, err.to_string()))?;
+
+        self.spellings.push(chord_spelling);
+
+        Ok(())
+    }
+
+    fn add_alias(
+        &mut self,
+        line: usize,
+        entry: &str,
+        alias: &str,
+        chord: &str,
+    ) -> Result<(), DictionaryError> {
+        self.entries = line;
+
+        let alias = alias.trim().trim_matches('"');
+        if alias.is_empty() {
+            return Err(DictionaryError::entry(
+                line,
+                entry,
+                "missing a name before `=`".to_string(),
+            ));
+        }
+
+        let chord = SlashChord::try_from(chord.trim()).map_err(|err| {
+            DictionaryError::entry(
+                line,
+                entry,
+                format!("`{}` is not a chord name ({})", chord.trim(), err.reason()),
+            )
+        })?;
+        self.aliases.push((alias.to_string(), chord));
+
+        Ok(())
+    }
+}
+
+// letter-based scale spellings for `MusicTheoryBauxBuilder::scale_dictionary`
+// (Note: entries replace the built-in spelling of the same scale - like chord entries, they only
+// reach the baux they are built into)
+#[derive(Debug, Clone, Default)]
+pub struct ScaleDictionary {
+    scales: Vec<ScaleSpelling>,
+    entries: usize,
+}
+
+impl ScaleDictionary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // one entry per line in the `major_minor_scales.txt` layout (ex: `"Gb Maj" = Gb,Ab,Bb,Cb,Db,Eb,F`)
+    pub fn parse(text: &str) -> Result<Self, DictionaryError> {
+        let mut dictionary = Self::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let entry = line.trim();
+            if is_blank(entry) {
+                continue;
+            }
+
+            let (name, notes) = entry.split_once('=').ok_or_else(|| {
+                DictionaryError::entry(index + 1, entry, "expected `\"name\" = notes`".to_string())
+            })?;
+            let notes = notes.split(',').map(str::trim).collect::<Vec<_>>();
+            dictionary.add_scale(index + 1, entry, name.trim().trim_matches('"'), &notes)?;
+        }
+
+        Ok(dictionary)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, DictionaryError> {
+        Self::parse(&read_dictionary(path.as_ref())?)
+    }
+
+    // in-memory entry (ex: `("F# Maj", &["F#", "G#", "A#", "B", "C#", "D#", "E#"])`)
+    pub fn with_scale(mut self, name: &str, notes: &[&str]) -> Result<Self, DictionaryError> {
+        let entry = format!("\"{}\" = {}", name, notes.join(","));
+        self.add_scale(self.entries + 1, &entry, name, notes)?;
+
+        Ok(self)
+    }
+
+    pub fn scales(&self) -> &Vec<ScaleSpelling> {
+        &self.scales
+    }
+
+    // notes must follow the quality's formula from the root (ex: `C Maj` -> `C, D, E, F, G, A, B`)
+    fn add_scale(
+        &mut self,
+        line: usize,
+        entry: &str,
+        name: &str,
+        notes: &[&str],
+    ) -> Result<(), DictionaryError> {
+        self.entries = line;
+
+        let scale_name = ScaleName::try_from(name).map_err(|_| {
+            DictionaryError::entry(line, entry, format!("`{}` is not a scale name", name))
+        })?;
+        let root = scale_name.get_root();
+        let formula = scale_name.get_quality().formula();
+        let scale_spelling = ScaleSpelling::new(&scale_name);
+
+        if notes.len() != formula.len() {
+            return Err(DictionaryError::entry(
+                line,
+                entry,
+                format!(
+                    "`{}` has `{}` notes, found `{}`",
+                    scale_name,
+                    formula.len(),
+                    notes.len()
+                ),
+            ));
+        }
+
+        let mut spelled_notes = Vec::with_capacity(notes.len());
+        for ((token, (_, semitones)), default) in notes
+            .iter()
+            .zip(&formula)
+            .zip(scale_spelling.spelled_notes())
+        {
+            let (note, spelled) = parse_note(token)
+                .ok_or_else(|| DictionaryError::entry(line, entry, not_a_note(token)))?;
+            let expected = NoteName::from_pitch_class(root.pitch_class() + semitones);
+
+            if note != expected {
+                return Err(DictionaryError::entry(
+                    line,
+                    entry,
+                    format!(
+                        "expected `{}` in `{}`, found `{}`",
+                        expected, scale_name, token
+                    ),
+                ));
+            }
+            spelled_notes.push(spelled.unwrap_or(*default));
+        }
+
+        let scale_spelling = scale_spelling
+            .with_spelled_notes(&spelled_notes)
+            .map_err(|err| DictionaryError::entry(line, entry, err.to_string()))?;
+        self.scales.push(scale_spelling);
+
+        Ok(())
+    }
+}
+
+// a letter-specific note (ex: `Eb`, `Cb`, `F##`) or either name of a pitch (ex: `D#/Eb`)
+fn parse_note(token: &str) -> Option<(NoteName, Option<SpelledNote>)> {
+    match SpelledNote::try_from(token) {
+        Ok(spelled) => Some((spelled.note_name(), Some(spelled))),
+        Err(_) => NoteName::try_from(token).ok().map(|note| (note, None)),
+    }
+}
+
+fn not_a_note(token: &str) -> String {
+    format!("`{}` is not a note name", token)
+}
+
+fn is_blank(entry: &str) -> bool {
+    entry.is_empty() || entry.starts_with('#') || entry.starts_with("//")
+}
+
+fn read_dictionary(path: &Path) -> Result<String, DictionaryError> {
+    std::fs::read_to_string(path)
+        .map_err(|err| DictionaryError::Io(path.display().to_string(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MusicTheoryBaux, ScaleQuality};
+
+    fn assert_entry_error(
+        result: Result<(), DictionaryError>,
+        text: &str,
+        line: usize,
+        reason: &str,
+    ) {
+        match result {
+            Err(DictionaryError::Entry {
+                line: error_line,
+                reason: error_reason,
+                ..
+            }) => {
+                assert_eq!(error_line, line, "{text}");
+                assert!(error_reason.contains(reason), "{text}: {error_reason}");
+            }
+            other => panic!("{text}: expected an entry error, found {other:?}"),
+        }
+    }
+
+    #[test]
+    fn bad_chord_entries_are_rejected() {
+        let bad_entry = |entry: &str, reason: &str| {
+            // the bad entry follows a comment, a blank line and a good entry
+            let text = format!("// house spellings\n\nC6: [C, E, G, A]\n{entry}");
+            let result = ChordDictionary::parse(&text).map(|_| ());
+
+            assert_entry_error(result, entry, 4, reason);
+        };
+
+        bad_entry("Cmaj7 [C, E, G, B]", "expected `name: [notes]`");
+        bad_entry("Cxyz: [C, E, G]", "is not a chord name");
+        bad_entry("Cmaj7: [C, E, H, B]", "is not a note name");
+        bad_entry("Cmaj7: [C, E, E, B]", "appears more than once");
+        bad_entry("Cmaj7: [C, E, F, B]", "is not a tone of");
+        bad_entry("Cmaj7: [E, C, G, B]", "the first note should be the root");
+        bad_entry(" = Cmaj7", "missing a name");
+    }
+
+    #[test]
+    fn bad_scale_entries_are_rejected() {
+        let result = ScaleDictionary::parse("C Maj: C,D,E,F,G,A,B").map(|_| ());
+        assert_entry_error(result, "colon", 1, "expected `\"name\" = notes`");
+
+        let result = ScaleDictionary::parse("\"C Foo\" = C,D,E,F,G,A,B").map(|_| ());
+        assert_entry_error(result, "unknown quality", 1, "is not a scale name");
+
+        let result = ScaleDictionary::parse("\"C Maj\" = C,D,E,F,G,A").map(|_| ());
+        assert_entry_error(result, "short", 1, "has `7` notes, found `6`");
+
+        // notes are checked against the scale's own pitches
+        let result = ScaleDictionary::parse("// ok\n\"C Maj\" = C,D,E,F#,G,A,B").map(|_| ());
+        assert_entry_error(result, "wrong pitch", 2, "expected `F` in");
+    }
+
+    #[test]
+    fn in_memory_entries_are_numbered() {
+        let result = ChordDictionary::new()
+            .with_chord("Cmaj7", &["C", "E", "G", "B"])
+            .and_then(|dictionary| dictionary.with_alias("", "Cmaj7"))
+            .map(|_| ());
+
+        assert_entry_error(result, "with_alias", 2, "missing a name");
+    }
+
+    #[test]
+    fn entries_reach_the_built_baux() {
+        let chords = ChordDictionary::parse(
+            "// house spellings\nD#min7: [D#, F#, A#, C#]\nSo What = Emin11\nmystery = Cmaj7/E",
+        )
+        .expect("should parse the chord dictionary");
+        let scales = ScaleDictionary::new()
+            .with_scale("F# Maj", &["F#", "G#", "A#", "B", "C#", "D#", "E#"])
+            .expect("should parse the scale entry");
+        let baux = MusicTheoryBaux::builder()
+            .chord_dictionary(chords)
+            .scale_dictionary(scales)
+            .build()
+            .expect("should build with dictionaries");
+
+        let d_sharp_min7 = ChordName::try_from("D#min7").expect("should parse the chord symbol");
+        let chord_spelling = baux
+            .get_chord_spelling_form(&d_sharp_min7, SpellingForm::Complete)
+            .expect("should have the dictionary spelling");
+        let spelled_notes = chord_spelling
+            .spelled_notes()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(spelled_notes, ["D#", "F#", "A#", "C#"]);
+
+        let so_what = baux.parse_chord("So What").expect("should read the alias");
+        assert_eq!(so_what.to_string(), "Emin11");
+        let mystery = baux
+            .parse_chord(" mystery ")
+            .expect("should read the alias");
+        assert_eq!(
+            mystery,
+            SlashChord::try_from("Cmaj7/E").expect("should parse")
+        );
+        assert!(baux.parse_chord("G7").is_ok());
+        assert!(baux.parse_chord("Not a chord").is_err());
+
+        let f_sharp_major = ScaleName::new(&NoteName::FSharpGFlat, ScaleQuality::Maj);
+        let scale_notes = baux
+            .get_scale_notes(&f_sharp_major)
+            .expect("should have the dictionary spelling");
+        assert_eq!(scale_notes[6].to_string(), "E#");
+    }
+
+    #[test]
+    fn named_chords_may_hold_colons() {
+        let dictionary = ChordDictionary::parse(
+            "Set 2: opener = Dm9
+Bb7: [Bb, D, Ab]",
+        )
+        .expect("should parse the chord dictionary");
+
+        let (alias, chord) = &dictionary.aliases()[0];
+        assert_eq!(alias, "Set 2: opener");
+        assert_eq!(chord.to_string(), "Dmin9");
+        assert_eq!(dictionary.spellings().len(), 1);
+        assert_eq!(dictionary.spellings()[0].form(), SpellingForm::Shell);
+
+        // a bad chord after the `=` is still reported as a named chord
+        let result = ChordDictionary::parse("intro: vamp = Hm7").map(|_| ());
+        assert!(matches!(
+            result,
+            Err(DictionaryError::Entry { line: 1, .. })
+        ));
+    }
+}
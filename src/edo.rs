@@ -0,0 +1,455 @@
+use crate::{music_theory_baux, ChordName, NoteOct};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, thiserror::Error)]
+pub enum EdoError {
+    #[error(
+        "ERROR: invalid octave division `{0}` (Note: an EDO needs at least one step per octave)"
+    )]
+    InvalidDivisions(usize),
+    #[error("ERROR: mixed pitch systems `{0}-EDO` and `{1}-EDO` (Note: notes, intervals and chords must share one EDO)")]
+    MismatchedDivisions(usize, usize),
+    #[error("ERROR: invalid ratio `{0}` (Note: interval ratios must be positive, finite values ex: `1.5` for `3/2`)")]
+    InvalidRatio(f64),
+    #[error("ERROR: no chord spelling found for `{0}`")]
+    UnknownChord(ChordName),
+    #[error("ERROR: an EDO chord needs at least one tone")]
+    EmptyChord,
+    #[error("ERROR: `{0}` distinct chord tones are too many to voice lead (Note: `EdoVoicing::voice_lead` takes up to `{1}` distinct tones)")]
+    TooManyTones(usize, usize),
+}
+
+// equal division of the octave into `divisions` steps (ex: `24` for quarter tones, `31` for 31-EDO)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edo {
+    divisions: usize,
+}
+
+impl Edo {
+    pub fn new(divisions: usize) -> Result<Self, EdoError> {
+        if divisions == 0 {
+            return Err(EdoError::InvalidDivisions(divisions));
+        }
+
+        Ok(Self { divisions })
+    }
+
+    pub fn divisions(&self) -> usize {
+        self.divisions
+    }
+
+    pub fn step_cents(&self) -> f64 {
+        1200.0 / self.divisions as f64
+    }
+
+    // step `0` is `C4` - each octave above or below adds/removes `divisions` steps
+    pub fn note(&self, step: i32) -> EdoNote {
+        EdoNote { edo: *self, step }
+    }
+
+    pub fn interval(&self, steps: i32) -> EdoInterval {
+        EdoInterval { edo: *self, steps }
+    }
+
+    // closest step to a 12-TET note (ex: `E4` in 31-EDO -> step `10`)
+    pub fn from_note_oct(&self, note: &NoteOct) -> EdoNote {
+        let semitones = (note.semitones() - NoteOct::C(4).semitones()) as f64;
+
+        self.note((semitones * self.divisions as f64 / 12.0).round() as i32)
+    }
+
+    pub fn approximate_cents(&self, cents: f64) -> EdoInterval {
+        self.interval((cents / self.step_cents()).round() as i32)
+    }
+
+    // closest interval to a just `ratio` (ex: `5/4` in 31-EDO -> `10\31`)
+    pub fn approximate_ratio(&self, ratio: f64) -> Result<EdoInterval, EdoError> {
+        if !ratio.is_finite() || ratio <= 0.0 {
+            return Err(EdoError::InvalidRatio(ratio));
+        }
+
+        Ok(self.approximate_cents(1200.0 * ratio.log2()))
+    }
+}
+
+impl Display for Edo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-EDO", self.divisions)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EdoInterval {
+    edo: Edo,
+    steps: i32,
+}
+
+impl EdoInterval {
+    pub fn edo(&self) -> Edo {
+        self.edo
+    }
+
+    pub fn steps(&self) -> i32 {
+        self.steps
+    }
+
+    pub fn cents(&self) -> f64 {
+        self.steps as f64 * self.edo.step_cents()
+    }
+
+    pub fn ratio(&self) -> f64 {
+        2f64.powf(self.cents() / 1200.0)
+    }
+}
+
+impl Display for EdoInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\\{}", self.steps, self.edo.divisions)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EdoNote {
+    edo: Edo,
+    step: i32,
+}
+
+impl EdoNote {
+    pub fn edo(&self) -> Edo {
+        self.edo
+    }
+
+    // steps from `C4`
+    pub fn step(&self) -> i32 {
+        self.step
+    }
+
+    pub fn octave(&self) -> i32 {
+        4 + self.step.div_euclid(self.edo.divisions as i32)
+    }
+
+    // position within the octave (`0..divisions`) - the EDO equivalent of a pitch class
+    pub fn step_class(&self) -> usize {
+        self.step.rem_euclid(self.edo.divisions as i32) as usize
+    }
+
+    // frequency (Hz) with `C4` sitting at its equal tempered pitch for `A4 = reference_pitch`
+    pub fn frequency(&self, reference_pitch: f64) -> f64 {
+        NoteOct::C(4).frequency(reference_pitch)
+            * 2f64.powf(self.step as f64 / self.edo.divisions as f64)
+    }
+
+    // closest 12-TET note + signed deviation in cents (ex: 24-EDO step `1` -> `C4`, `+50.0`)
+    pub fn nearest_note_oct(&self) -> Option<(NoteOct, f64)> {
+        let cents = self.step as f64 * self.edo.step_cents();
+        let semitones = (cents / 100.0).round();
+        let index = NoteOct::C(4).semitones() as i64 + semitones as i64;
+
+        let note = NoteOct::from_semitone_index(usize::try_from(index).ok()?)?;
+        Some((note, cents - semitones * 100.0))
+    }
+
+    pub fn transpose(&self, interval: &EdoInterval) -> Result<EdoNote, EdoError> {
+        self.check_edo(&interval.edo)?;
+
+        Ok(self.edo.note(self.step + interval.steps))
+    }
+
+    // signed interval up to `other` (negative when `other` sits below)
+    pub fn interval_to(&self, other: &EdoNote) -> Result<EdoInterval, EdoError> {
+        self.check_edo(&other.edo)?;
+
+        Ok(self.edo.interval(other.step - self.step))
+    }
+
+    fn check_edo(&self, other: &Edo) -> Result<(), EdoError> {
+        if self.edo != *other {
+            return Err(EdoError::MismatchedDivisions(
+                self.edo.divisions,
+                other.divisions,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for EdoNote {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\\{}({})",
+            self.step_class(),
+            self.edo.divisions,
+            self.octave()
+        )
+    }
+}
+
+// root + intervals above it (ex: 24-EDO neutral triad `0\24`, `7\24`, `14\24`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdoChordSpelling {
+    root: EdoNote,
+    intervals: Vec<EdoInterval>,
+}
+
+impl EdoChordSpelling {
+    // Note: `steps` are measured from the root, the root itself (`0`) is always included
+    pub fn from_steps(root: EdoNote, steps: &[i32]) -> Self {
+        let mut intervals = vec![root.edo.interval(0)];
+        intervals.extend(
+            steps
+                .iter()
+                .filter(|steps| **steps != 0)
+                .map(|steps| root.edo.interval(*steps)),
+        );
+
+        Self { root, intervals }
+    }
+
+    // just `ratios` above the root, each approximated by the closest step (ex: `[5/4, 3/2, 7/4]`)
+    pub fn from_ratios(root: EdoNote, ratios: &[f64]) -> Result<Self, EdoError> {
+        let steps = ratios
+            .iter()
+            .map(|ratio| root.edo.approximate_ratio(*ratio).map(|iv| iv.steps))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::from_steps(root, &steps))
+    }
+
+    // 12-TET chord (via `music_theory_baux` spellings) with each tone moved to its closest step
+    pub fn from_chord_name(root: EdoNote, chord: &ChordName) -> Result<Self, EdoError> {
+        let spelling = music_theory_baux
+            .get_chord_spelling(chord)
+            .ok_or(EdoError::UnknownChord(*chord))?;
+        let root_pitch = chord.get_root().pitch_class();
+
+        let steps = spelling
+            .spelling()
+            .iter()
+            .map(|chord_tone| {
+                let semitones = (chord_tone.note().pitch_class() + 12 - root_pitch) % 12;
+                root.edo.approximate_cents(semitones as f64 * 100.0).steps
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Self::from_steps(root, &steps))
+    }
+
+    pub fn root(&self) -> EdoNote {
+        self.root
+    }
+
+    pub fn intervals(&self) -> &Vec<EdoInterval> {
+        &self.intervals
+    }
+
+    pub fn notes(&self) -> Vec<EdoNote> {
+        self.intervals
+            .iter()
+            .map(|interval| self.root.edo.note(self.root.step + interval.steps))
+            .collect()
+    }
+}
+
+impl Display for EdoChordSpelling {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let intervals = self
+            .intervals
+            .iter()
+            .map(|interval| interval.to_string())
+            .collect::<Vec<_>>()
+            .join(" -- ");
+
+        write!(f, "{} chord on {}: {}", self.root.edo, self.root, intervals)
+    }
+}
+
+// placed EDO voices that move to following chords by the smallest total number of steps
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdoVoicing {
+    edo: Edo,
+    voices: Vec<EdoNote>,
+}
+
+impl EdoVoicing {
+    // distinct chord tones `voice_lead` can spread the voices over
+    pub const MAX_TONES: usize = 16;
+
+    pub fn new(voices: &[EdoNote]) -> Result<Self, EdoError> {
+        let edo = voices.first().ok_or(EdoError::EmptyChord)?.edo;
+        if let Some(other) = voices.iter().find(|voice| voice.edo != edo) {
+            return Err(EdoError::MismatchedDivisions(
+                edo.divisions,
+                other.edo.divisions,
+            ));
+        }
+
+        Ok(Self {
+            edo,
+            voices: voices.to_vec(),
+        })
+    }
+
+    pub fn from_chord(chord: &EdoChordSpelling) -> Self {
+        Self {
+            edo: chord.root.edo,
+            voices: chord.notes(),
+        }
+    }
+
+    pub fn edo(&self) -> Edo {
+        self.edo
+    }
+
+    pub fn voices(&self) -> &Vec<EdoNote> {
+        &self.voices
+    }
+
+    pub fn frequencies(&self, reference_pitch: f64) -> Vec<f64> {
+        self.voices
+            .iter()
+            .map(|voice| voice.frequency(reference_pitch))
+            .collect()
+    }
+
+    pub fn voice_lead(&self, next_chord: &EdoChordSpelling) -> Result<EdoVoicing, EdoError> {
+        self.voices[0].check_edo(&next_chord.root.edo)?;
+
+        let mut targets = Vec::new();
+        for note in next_chord.notes() {
+            if !targets.contains(&note.step_class()) {
+                targets.push(note.step_class());
+            }
+        }
+
+        if targets.len() > EdoVoicing::MAX_TONES {
+            return Err(EdoError::TooManyTones(targets.len(), EdoVoicing::MAX_TONES));
+        }
+
+        // voice by voice, the least total movement (in steps) for each set of covered chord
+        // tones - `(movement, previous set, target)` per set, so the winner can be traced back
+        let sets = 1 << targets.len();
+        let mut table = vec![vec![None; sets]];
+        table[0][0] = Some((0, 0, 0));
+
+        for voice in &self.voices {
+            let previous = &table[table.len() - 1];
+            let mut current: Vec<Option<(i32, usize, usize)>> = vec![None; sets];
+
+            for (set, entry) in previous.iter().enumerate() {
+                let Some((movement, ..)) = entry else {
+                    continue;
+                };
+                for (target_index, target) in targets.iter().enumerate() {
+                    let moved = self.nearest_step(voice, *target);
+                    let movement = movement + (moved.step - voice.step).abs();
+                    let next = set | 1 << target_index;
+
+                    if current[next].is_none_or(|(best, ..)| movement < best) {
+                        current[next] = Some((movement, set, target_index));
+                    }
+                }
+            }
+            table.push(current);
+        }
+
+        // prefer sounding the most chord tones, then the least movement
+        let (mut set, _) = table[table.len() - 1]
+            .iter()
+            .enumerate()
+            .filter_map(|(set, entry)| entry.map(|(movement, ..)| (set, movement)))
+            .min_by_key(|(set, movement)| (std::cmp::Reverse(set.count_ones()), *movement))
+            .ok_or(EdoError::EmptyChord)?;
+
+        let mut voices = Vec::with_capacity(self.voices.len());
+        for (index, voice) in self.voices.iter().enumerate().rev() {
+            let (_, previous, target_index) =
+                table[index + 1][set].expect("should trace back through filled sets");
+            voices.push(self.nearest_step(voice, targets[target_index]));
+            set = previous;
+        }
+        voices.reverse();
+
+        Ok(EdoVoicing {
+            edo: self.edo,
+            voices,
+        })
+    }
+
+    // closest placement of `step_class` to `voice` (ties move down)
+    fn nearest_step(&self, voice: &EdoNote, step_class: usize) -> EdoNote {
+        let divisions = self.edo.divisions as i32;
+        let up = (step_class as i32 - voice.step_class() as i32).rem_euclid(divisions);
+        let down = up - divisions;
+        let diff = if up < -down { up } else { down };
+
+        self.edo.note(voice.step + diff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    // `(distinct step classes, total movement)` of a voice leading
+    fn score(from: &EdoVoicing, to: &[EdoNote]) -> (usize, i32) {
+        let covered = to.iter().map(EdoNote::step_class).collect::<HashSet<_>>();
+        let movement = from
+            .voices()
+            .iter()
+            .zip(to)
+            .map(|(voice, moved)| (moved.step - voice.step).abs())
+            .sum();
+
+        (covered.len(), movement)
+    }
+
+    #[test]
+    fn voice_lead_matches_every_assignment() {
+        let edo = Edo::new(24).unwrap();
+        let voicing =
+            EdoVoicing::new(&[edo.note(0), edo.note(14), edo.note(31), edo.note(38)]).unwrap();
+        let next = EdoChordSpelling::from_steps(edo.note(5), &[7, 14, 19]);
+        let targets = next
+            .notes()
+            .iter()
+            .map(EdoNote::step_class)
+            .collect::<Vec<_>>();
+
+        // every voice -> chord tone assignment, most tones first, then least movement
+        let best = (0..targets.len().pow(4))
+            .map(|assignment| {
+                let moved = (0..4)
+                    .map(|voice| {
+                        let target = targets[assignment / targets.len().pow(voice) % targets.len()];
+                        voicing.nearest_step(&voicing.voices()[voice as usize], target)
+                    })
+                    .collect::<Vec<_>>();
+                score(&voicing, &moved)
+            })
+            .max_by_key(|(covered, movement)| (*covered, -movement))
+            .unwrap();
+
+        let led = voicing.voice_lead(&next).unwrap();
+        assert_eq!(score(&voicing, led.voices()), best);
+    }
+
+    #[test]
+    fn voice_lead_handles_large_chords() {
+        let edo = Edo::new(31).unwrap();
+        let voices = (0..12).map(|voice| edo.note(voice * 9)).collect::<Vec<_>>();
+        let voicing = EdoVoicing::new(&voices).unwrap();
+        let next = EdoChordSpelling::from_steps(edo.note(2), &[5, 10, 13, 18, 23, 28]);
+
+        let led = voicing.voice_lead(&next).unwrap();
+        assert_eq!(score(&voicing, led.voices()).0, 7);
+
+        let cluster = EdoChordSpelling::from_steps(edo.note(0), &(1..20).collect::<Vec<_>>());
+        assert!(matches!(
+            voicing.voice_lead(&cluster),
+            Err(EdoError::TooManyTones(20, EdoVoicing::MAX_TONES))
+        ));
+    }
+}
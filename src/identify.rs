@@ -0,0 +1,231 @@
+use crate::{
+    music_theory_baux, ChordQuality, ChordSpelling, ChordTone, ChordToneDegree, NoteName,
+    SlashChord, SpellingForm,
+};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+// one reading of a set of notes (ex: `E`, `G`, `C` -> `Cmaj/E`, first inversion, nothing omitted)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChordCandidate {
+    chord: SlashChord,
+    omitted: Vec<ChordTone>,
+    // bass note outside of the chord's own tones (ex: the `G` of `Amin/G`)
+    foreign_bass: bool,
+}
+
+impl ChordCandidate {
+    pub fn chord(&self) -> SlashChord {
+        self.chord
+    }
+
+    // see `SlashChord::inversion` - `None` for a foreign bass
+    pub fn inversion(&self) -> Option<usize> {
+        self.chord.inversion()
+    }
+
+    // chord tones (complete spelling) missing from the notes (ex: `C`, `E`, `Bb` -> `C7` without its `G`)
+    pub fn omitted(&self) -> &Vec<ChordTone> {
+        &self.omitted
+    }
+
+    pub fn has_foreign_bass(&self) -> bool {
+        self.foreign_bass
+    }
+
+    // lower ranks first: chord-tone basses, fewest omitted / altered tones, root kept, lowest
+    // inversion, smallest chord
+    fn rank(&self) -> (bool, usize, bool, usize, usize) {
+        let omits_root = self
+            .omitted
+            .iter()
+            .any(|chord_tone| chord_tone.get_tone_degree() == ChordToneDegree::Root);
+        let alterations = match self.chord.chord().get_quality() {
+            ChordQuality::Altered(_, tensions) => tensions.alterations().len(),
+            _ => 0,
+        };
+        let size = music_theory_baux
+            .get_chord_spelling_form(&self.chord.chord(), SpellingForm::Complete)
+            .map_or(0, |chord_spelling| chord_spelling.spelling().len());
+
+        (
+            self.foreign_bass,
+            self.omitted.len() + alterations,
+            omits_root,
+            self.inversion().unwrap_or(usize::MAX),
+            size,
+        )
+    }
+}
+
+impl Display for ChordCandidate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.chord)?;
+
+        if !self.omitted.is_empty() {
+            let omitted = self
+                .omitted
+                .iter()
+                .map(|chord_tone| chord_tone.get_tone_degree().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, " (no {})", omitted)?;
+        }
+
+        Ok(())
+    }
+}
+
+// ranked chord readings of `notes`, best first - the first note is taken as the bass
+// (ex: `[E, G, C]` -> `Cmaj/E`, then `Amin7/E (no Root)`, ...)
+// (Note: only the root, an unaltered fifth, ninth or eleventh may be left out of a reading)
+pub fn identify_chord(notes: &[NoteName]) -> Vec<ChordCandidate> {
+    let Some(bass) = notes.first() else {
+        return Vec::new();
+    };
+    let pitch_classes = notes.iter().copied().collect::<HashSet<_>>();
+
+    let mut candidates = music_theory_baux
+        .complete_chord_spellings()
+        .values()
+        .filter_map(|chord_spelling| read_chord(chord_spelling, &pitch_classes, bass))
+        .collect::<Vec<_>>();
+
+    candidates.sort_by_cached_key(|candidate| (candidate.rank(), candidate.chord.to_string()));
+
+    candidates
+}
+
+fn read_chord(
+    chord_spelling: &ChordSpelling,
+    pitch_classes: &HashSet<NoteName>,
+    bass: &NoteName,
+) -> Option<ChordCandidate> {
+    let root = chord_spelling.name().get_root();
+    let tones = chord_spelling
+        .spelling()
+        .iter()
+        .map(ChordTone::note)
+        .copied()
+        .collect::<HashSet<_>>();
+
+    // tensions sharing a pitch (ex: the `#5` and `b13` of `Cmaj7#5b13`) can't be told apart
+    if tones.len() < chord_spelling.spelling().len() {
+        return None;
+    }
+
+    let foreign_bass = !tones.contains(bass);
+    let covers_notes = pitch_classes
+        .iter()
+        .all(|note| tones.contains(note) || (foreign_bass && note == bass));
+    if !covers_notes || pitch_classes.len() < 2 {
+        return None;
+    }
+
+    let omitted = chord_spelling
+        .spelling()
+        .iter()
+        .filter(|chord_tone| !pitch_classes.contains(chord_tone.note()))
+        .copied()
+        .collect::<Vec<_>>();
+    let highest = chord_spelling
+        .spelling()
+        .iter()
+        .map(|chord_tone| chord_tone.get_tone_degree().number())
+        .max()
+        .unwrap_or(1);
+    let can_omit = omitted.iter().all(|chord_tone| {
+        let semitones = (chord_tone.note().pitch_class() + 12 - root.pitch_class()) % 12;
+
+        match chord_tone.get_tone_degree() {
+            ChordToneDegree::Root => true,
+            ChordToneDegree::Fifth => semitones == 7,
+            // inner extensions of 11th / 13th chords (ex: the `9` of `C13`)
+            degree @ (ChordToneDegree::Ninth | ChordToneDegree::Eleventh) => {
+                degree.number() < highest
+            }
+            _ => false,
+        }
+    });
+    // a foreign bass needs the full chord above it to be heard as a slash chord
+    if !can_omit || (foreign_bass && !omitted.is_empty()) {
+        return None;
+    }
+
+    Some(ChordCandidate {
+        chord: SlashChord::new(*chord_spelling.name(), bass),
+        omitted,
+        foreign_bass,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn best_reading(notes: &[NoteName]) -> String {
+        identify_chord(notes)
+            .first()
+            .expect("should read the notes as a chord")
+            .to_string()
+    }
+
+    #[test]
+    fn complete_chords_read_in_root_position() {
+        use NoteName::*;
+
+        assert_eq!(best_reading(&[C, E, G, B]), "Cmaj7");
+        assert_eq!(best_reading(&[G, A, C, E]), "C6/G");
+    }
+
+    #[test]
+    fn a_missing_fifth_is_named() {
+        let candidates = identify_chord(&[NoteName::C, NoteName::E, NoteName::ASharpBFlat]);
+
+        assert_eq!(candidates[0].to_string(), "C7 (no Fifth)");
+        assert_eq!(
+            candidates[0]
+                .omitted()
+                .iter()
+                .map(|chord_tone| *chord_tone.note())
+                .collect::<Vec<_>>(),
+            [NoteName::G]
+        );
+    }
+
+    #[test]
+    fn first_inversion_reads_with_its_bass() {
+        let candidates = identify_chord(&[NoteName::E, NoteName::G, NoteName::C]);
+        let best = &candidates[0];
+
+        assert_eq!(best.chord().to_string(), "Cmaj/E");
+        assert_eq!(best.inversion(), Some(1));
+        assert!(best.omitted().is_empty());
+        assert!(!best.has_foreign_bass());
+        // rootless readings come after the complete triad
+        assert_eq!(candidates[1].to_string(), "Amin7/E (no Root)");
+    }
+
+    #[test]
+    fn foreign_basses_rank_last() {
+        let candidates = identify_chord(&[NoteName::D, NoteName::C, NoteName::E, NoteName::G]);
+        let first_foreign = candidates
+            .iter()
+            .position(ChordCandidate::has_foreign_bass)
+            .expect("should read `C` over a `D` bass");
+
+        assert_eq!(candidates[0].to_string(), "Cadd9/D");
+        assert_eq!(candidates[first_foreign].to_string(), "Cmaj/D");
+        assert_eq!(candidates[first_foreign].inversion(), None);
+        assert!(candidates[first_foreign..]
+            .iter()
+            .all(ChordCandidate::has_foreign_bass));
+    }
+
+    #[test]
+    fn fewer_than_two_notes_read_as_nothing() {
+        assert!(identify_chord(&[]).is_empty());
+        assert!(identify_chord(&[NoteName::C]).is_empty());
+        assert!(identify_chord(&[NoteName::C, NoteName::C]).is_empty());
+    }
+}
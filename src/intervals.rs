@@ -0,0 +1,363 @@
+use crate::{NoteOct, SpelledNote};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+
+#[derive(Debug, thiserror::Error)]
+#[error("Error trying in conversion of `{0}` to `Interval` (Note: expects a quality symbol [`P`, `M`, `m`, `A`, `d`] + interval number ex: `P5`, `m3`, `A4`, `M9` - perfect qualities for unisons, 4ths, 5ths and octaves only)")]
+pub struct IntervalError(String);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalQuality {
+    Perfect,
+    Major,
+    Minor,
+    Augmented,
+    Diminished,
+}
+
+impl Display for IntervalQuality {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntervalQuality::Perfect => write!(f, "P"),
+            IntervalQuality::Major => write!(f, "M"),
+            IntervalQuality::Minor => write!(f, "m"),
+            IntervalQuality::Augmented => write!(f, "A"),
+            IntervalQuality::Diminished => write!(f, "d"),
+        }
+    }
+}
+
+// quality + number (ex: major third, perfect fifth, minor ninth)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    quality: IntervalQuality,
+    number: usize,
+}
+
+impl Interval {
+    pub fn new(quality: IntervalQuality, number: usize) -> Result<Self, IntervalError> {
+        let interval = Self { quality, number };
+
+        let is_valid = number > 0
+            && match quality {
+                IntervalQuality::Perfect => is_perfect_number(number),
+                IntervalQuality::Major | IntervalQuality::Minor => !is_perfect_number(number),
+                // a diminished unison would sit below its own root
+                IntervalQuality::Augmented => true,
+                IntervalQuality::Diminished => number != 1,
+            };
+
+        if !is_valid {
+            return Err(IntervalError(interval.to_string()));
+        }
+
+        Ok(interval)
+    }
+
+    // common name for a semitone distance (ex: `7` -> `P5`, `6` -> `A4`, `14` -> `M9`)
+    pub fn from_semitones(semitones: usize) -> Self {
+        let (quality, number) = match semitones % 12 {
+            0 => (IntervalQuality::Perfect, 1),
+            1 => (IntervalQuality::Minor, 2),
+            2 => (IntervalQuality::Major, 2),
+            3 => (IntervalQuality::Minor, 3),
+            4 => (IntervalQuality::Major, 3),
+            5 => (IntervalQuality::Perfect, 4),
+            6 => (IntervalQuality::Augmented, 4),
+            7 => (IntervalQuality::Perfect, 5),
+            8 => (IntervalQuality::Minor, 6),
+            9 => (IntervalQuality::Major, 6),
+            10 => (IntervalQuality::Minor, 7),
+            _ => (IntervalQuality::Major, 7),
+        };
+
+        Self {
+            quality,
+            number: number + 7 * (semitones / 12),
+        }
+    }
+
+    // letter-based ascending interval from `lower` up to `upper` within one octave
+    // (ex: `C` -> `Eb` is `m3`, `C` -> `D#` is `A2`) - `None` past augmented/diminished
+    pub fn between(lower: &SpelledNote, upper: &SpelledNote) -> Option<Self> {
+        let number =
+            (upper.letter().index() as i32 - lower.letter().index() as i32).rem_euclid(7) + 1;
+        let semitones = (upper.note_name().pitch_class() as i32
+            - lower.note_name().pitch_class() as i32)
+            .rem_euclid(12);
+        // fold into `-6..6` around the major/perfect size (ex: `Cb` above `C` is `-1`, not `11`)
+        let diff = (semitones - base_semitones(number as usize) as i32 + 6).rem_euclid(12) - 6;
+
+        let quality = match (is_perfect_number(number as usize), diff) {
+            (true, 0) => IntervalQuality::Perfect,
+            (false, 0) => IntervalQuality::Major,
+            (false, -1) => IntervalQuality::Minor,
+            (_, 1) => IntervalQuality::Augmented,
+            (true, -1) | (false, -2) => IntervalQuality::Diminished,
+            _ => return None,
+        };
+
+        Interval::new(quality, number as usize).ok()
+    }
+
+    pub fn quality(&self) -> IntervalQuality {
+        self.quality
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    // wider than an octave (ex: `M9`, `P11`)
+    pub fn is_compound(&self) -> bool {
+        self.number > 8
+    }
+
+    // compound intervals reduced to within an octave (ex: `M9` -> `M2`, `P15` -> `P8`)
+    pub fn simple(&self) -> Self {
+        if !self.is_compound() {
+            return *self;
+        }
+
+        let number = match (self.number - 1) % 7 + 1 {
+            1 => 8,
+            number => number,
+        };
+
+        Self {
+            quality: self.quality,
+            number,
+        }
+    }
+
+    // the interval that completes an octave (ex: `M3` -> `m6`, `A4` -> `d5`, `M9` -> `m7`)
+    // (Note: augmented octaves invert to a diminished unison, which `new` rejects)
+    pub fn invert(&self) -> Self {
+        let simple = self.simple();
+        let quality = match simple.quality {
+            IntervalQuality::Perfect => IntervalQuality::Perfect,
+            IntervalQuality::Major => IntervalQuality::Minor,
+            IntervalQuality::Minor => IntervalQuality::Major,
+            IntervalQuality::Augmented => IntervalQuality::Diminished,
+            IntervalQuality::Diminished => IntervalQuality::Augmented,
+        };
+
+        Self {
+            quality,
+            number: 9 - simple.number,
+        }
+    }
+
+    // Note: sized in `i32` before the octaves are added - a diminished octave is `P1` less
+    // one semitone plus an octave (`11`), the only negative size (`d1`) is rejected by `new`
+    pub fn semitones(&self) -> usize {
+        let octaves = ((self.number - 1) / 7) as i32;
+        let base = base_semitones((self.number - 1) % 7 + 1) as i32;
+        let adjustment = match (is_perfect_number(self.number), self.quality) {
+            (_, IntervalQuality::Perfect) | (_, IntervalQuality::Major) => 0,
+            (_, IntervalQuality::Minor) => -1,
+            (_, IntervalQuality::Augmented) => 1,
+            (true, IntervalQuality::Diminished) => -1,
+            (false, IntervalQuality::Diminished) => -2,
+        };
+
+        (base + adjustment + 12 * octaves) as usize
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.quality, self.number)
+    }
+}
+
+impl TryFrom<&str> for Interval {
+    type Error = IntervalError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut chars = value.chars();
+
+        let quality = match chars.next() {
+            Some('P') => IntervalQuality::Perfect,
+            Some('M') => IntervalQuality::Major,
+            Some('m') => IntervalQuality::Minor,
+            Some('A') => IntervalQuality::Augmented,
+            Some('d') => IntervalQuality::Diminished,
+            _ => return Err(IntervalError(value.to_string())),
+        };
+        let number = chars
+            .as_str()
+            .parse::<usize>()
+            .map_err(|_| IntervalError(value.to_string()))?;
+
+        Interval::new(quality, number).map_err(|_| IntervalError(value.to_string()))
+    }
+}
+
+// distance between two notes, always measured from the lower note up
+// (Note: unsigned - `C4 - E4` and `E4 - C4` are both `M3`, compare the notes for the direction)
+impl Sub for NoteOct {
+    type Output = Interval;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Interval::from_semitones(self.semitones().abs_diff(rhs.semitones()) as usize)
+    }
+}
+
+// `None` past the highest supported note (ex: `B9` + `M3`) - see `NoteOct::OCTAVES`
+impl Add<Interval> for NoteOct {
+    type Output = Option<NoteOct>;
+
+    fn add(self, rhs: Interval) -> Self::Output {
+        NoteOct::from_semitone_index(self.semitone_index()? + rhs.semitones())
+    }
+}
+
+// unisons, fourths, fifths and octaves (and their compounds) take perfect qualities
+fn is_perfect_number(number: usize) -> bool {
+    matches!((number - 1) % 7 + 1, 1 | 4 | 5)
+}
+
+// semitones of the major/perfect interval for a simple `number` (`1..=7`)
+fn base_semitones(number: usize) -> usize {
+    match number {
+        1 => 0,
+        2 => 2,
+        3 => 4,
+        4 => 5,
+        5 => 7,
+        6 => 9,
+        _ => 11,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(name: &str) -> Interval {
+        Interval::try_from(name).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    #[test]
+    fn sizes_follow_quality_and_number() {
+        assert_eq!(interval("P1").semitones(), 0);
+        assert_eq!(interval("m2").semitones(), 1);
+        assert_eq!(interval("A2").semitones(), 3);
+        assert_eq!(interval("d3").semitones(), 2);
+        assert_eq!(interval("d4").semitones(), 4);
+        assert_eq!(interval("A4").semitones(), 6);
+        assert_eq!(interval("d7").semitones(), 9);
+        assert_eq!(interval("d8").semitones(), 11);
+        assert_eq!(interval("P8").semitones(), 12);
+        assert_eq!(interval("m9").semitones(), 13);
+        assert_eq!(interval("A11").semitones(), 18);
+        assert_eq!(interval("d15").semitones(), 23);
+    }
+
+    #[test]
+    fn inversions_complete_the_octave() {
+        assert_eq!(interval("M3").invert(), interval("m6"));
+        assert_eq!(interval("A4").invert(), interval("d5"));
+        assert_eq!(interval("P1").invert(), interval("P8"));
+        assert_eq!(interval("A1").invert(), interval("d8"));
+        // compound intervals invert their simple interval
+        assert_eq!(interval("M9").simple(), interval("M2"));
+        assert_eq!(interval("M9").invert(), interval("m7"));
+        assert_eq!(interval("P15").simple(), interval("P8"));
+        assert!(!interval("P8").is_compound());
+
+        let qualities = [
+            IntervalQuality::Perfect,
+            IntervalQuality::Major,
+            IntervalQuality::Minor,
+            IntervalQuality::Augmented,
+            IntervalQuality::Diminished,
+        ];
+        let augmented_octave = interval("A8");
+        for number in 1..=15 {
+            for interval in qualities
+                .iter()
+                .filter_map(|quality| Interval::new(*quality, number).ok())
+                .filter(|candidate| candidate.simple() != augmented_octave)
+            {
+                let inversion = interval.invert();
+
+                assert_eq!(inversion.invert(), interval.simple(), "`{interval}`");
+                assert_eq!(
+                    (interval.semitones() + inversion.semitones()) % 12,
+                    0,
+                    "`{interval}` + `{inversion}`"
+                );
+                assert_eq!(
+                    Interval::try_from(interval.to_string().as_str()).unwrap(),
+                    interval
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn spelled_notes_name_their_interval() {
+        let note = |text: &str| SpelledNote::try_from(text).unwrap();
+
+        assert_eq!(
+            Interval::between(&note("C"), &note("Eb")),
+            Some(interval("m3"))
+        );
+        assert_eq!(
+            Interval::between(&note("C"), &note("D#")),
+            Some(interval("A2"))
+        );
+        assert_eq!(
+            Interval::between(&note("B"), &note("F")),
+            Some(interval("d5"))
+        );
+        assert_eq!(
+            Interval::between(&note("E"), &note("C")),
+            Some(interval("m6"))
+        );
+        assert_eq!(
+            Interval::between(&note("C"), &note("Ebb")),
+            Some(interval("d3"))
+        );
+        assert_eq!(Interval::between(&note("C"), &note("E##")), None);
+        assert_eq!(Interval::from_semitones(14), interval("M9"));
+    }
+
+    #[test]
+    fn invalid_intervals_are_rejected() {
+        for name in ["d1", "P3", "M5", "m4", "P0", "M", "X3", "P1.5"] {
+            assert!(Interval::try_from(name).is_err(), "`{}`", name);
+        }
+    }
+
+    #[test]
+    fn note_arithmetic_stays_in_range() {
+        let m3 = Interval::try_from("m3").unwrap();
+        assert_eq!(NoteOct::A(4) + m3, Some(NoteOct::C(5)));
+        assert_eq!(NoteOct::GSharpAFlat(9) + m3, Some(NoteOct::B(9)));
+        assert_eq!(NoteOct::A(9) + m3, None);
+        assert_eq!(
+            NoteOct::B(9) + Interval::try_from("P1").unwrap(),
+            Some(NoteOct::B(9))
+        );
+        assert_eq!(NoteOct::B(9) + Interval::try_from("M3").unwrap(), None);
+        assert_eq!(NoteOct::C(-3) + m3, None);
+
+        let compound = NoteOct::C(-2) + Interval::try_from("M10").unwrap();
+        assert_eq!(compound, Some(NoteOct::E(-1)));
+    }
+
+    #[test]
+    fn note_distances_are_unsigned() {
+        let up = NoteOct::E(4) - NoteOct::C(4);
+        let down = NoteOct::C(4) - NoteOct::E(4);
+        assert_eq!(up, down);
+        assert_eq!(up.to_string(), "M3");
+
+        assert_eq!((NoteOct::C(5) - NoteOct::C(4)).to_string(), "P8");
+        assert_eq!((NoteOct::C(4) - NoteOct::C(4)).to_string(), "P1");
+        assert_eq!((NoteOct::G(9) - NoteOct::C(-2)).semitones(), 139);
+    }
+}
@@ -0,0 +1,354 @@
+use crate::{Accidental, ChordName, ChordQuality, Letter, NoteName, ScaleQuality, SpelledNote};
+use std::fmt::{Display, Formatter};
+
+// a tonal center used as context for letter-based spellings (ex: `B` major spells `D#`, not `Eb`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Key {
+    tonic: SpelledNote,
+    quality: ScaleQuality,
+}
+
+impl Key {
+    pub fn new(tonic: SpelledNote, quality: ScaleQuality) -> Self {
+        Self { tonic, quality }
+    }
+
+    pub fn tonic(&self) -> SpelledNote {
+        self.tonic
+    }
+
+    pub fn quality(&self) -> ScaleQuality {
+        self.quality
+    }
+
+    pub fn scale_notes(&self) -> Vec<SpelledNote> {
+        spell_degrees(self.tonic, &self.quality.formula())
+    }
+
+    // the key's own spelling of `note` - `None` for notes outside of the key's scale
+    pub fn get_diatonic_spelling(&self, note: &NoteName) -> Option<SpelledNote> {
+        self.scale_notes()
+            .into_iter()
+            .find(|spelled| spelled.note_name() == *note)
+    }
+
+    // diatonic notes take the key's letter, chromatic notes lean toward the key signature's accidentals
+    pub fn spell_note(&self, note: &NoteName) -> SpelledNote {
+        if let Some(spelled) = self.get_diatonic_spelling(note) {
+            return spelled;
+        }
+
+        let spellings = SpelledNote::spellings(note);
+        if let Some(natural) = spellings
+            .iter()
+            .find(|spelled| spelled.accidental() == Accidental::Natural)
+        {
+            return *natural;
+        }
+
+        let scale_notes = self.scale_notes();
+        let flats = scale_notes
+            .iter()
+            .filter(|spelled| spelled.accidental().semitones() < 0)
+            .count();
+        let sharps = scale_notes
+            .iter()
+            .filter(|spelled| spelled.accidental().semitones() > 0)
+            .count();
+
+        let preferred = match flats.cmp(&sharps) {
+            std::cmp::Ordering::Greater => Accidental::Flat,
+            std::cmp::Ordering::Less => Accidental::Sharp,
+            std::cmp::Ordering::Equal => return SpelledNote::from(*note),
+        };
+
+        spellings
+            .into_iter()
+            .find(|spelled| spelled.accidental() == preferred)
+            .unwrap_or_else(|| SpelledNote::from(*note))
+    }
+
+    // chord stacked in thirds from scale `degree` (`1..=7`) - `None` outside of seven-note keys
+    // (ex: degree `2` of `C` major -> `Dmin`, or `Dmin7` with its `seventh`)
+    pub fn diatonic_chord(&self, degree: usize, seventh: bool) -> Option<ChordName> {
+        let formula = self.quality.formula();
+        if formula.len() != 7 || !(1..=7).contains(&degree) {
+            return None;
+        }
+
+        let offset = formula[degree - 1].1;
+        let above = |steps: usize| (formula[(degree - 1 + steps) % 7].1 + 12 - offset) % 12;
+        let quality = match (above(2), above(4), seventh.then(|| above(6))) {
+            (4, 7, None) => ChordQuality::Maj,
+            (3, 7, None) => ChordQuality::Min,
+            (4, 8, None) => ChordQuality::Aug,
+            (3, 6, None) => ChordQuality::Dim,
+            (4, 7, Some(11)) => ChordQuality::MajSev,
+            (4, 7, Some(10)) => ChordQuality::Sev,
+            (3, 7, Some(10)) => ChordQuality::MinSev,
+            (3, 7, Some(11)) => ChordQuality::MinMajSev,
+            (3, 6, Some(10)) => ChordQuality::HalfDim,
+            (3, 6, Some(9)) => ChordQuality::DimSev,
+            (4, 8, Some(11)) => ChordQuality::AugMajSev,
+            (4, 8, Some(10)) => ChordQuality::AugSev,
+            _ => return None,
+        };
+
+        let root = NoteName::from_pitch_class(self.tonic.note_name().pitch_class() + offset);
+        Some(ChordName::new(&root, quality))
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.tonic, self.quality)
+    }
+}
+
+//
+//
+//
+
+// spells each `(degree number, semitones above root)` pair by letter, starting from `root`
+pub(crate) fn spell_degrees(root: SpelledNote, degrees: &[(usize, usize)]) -> Vec<SpelledNote> {
+    degrees
+        .iter()
+        .map(|(degree, semitones)| {
+            root.above(*degree, *semitones).unwrap_or_else(|| {
+                // past a double accidental - fall back to the default spelling
+                let note = NoteName::from_pitch_class(root.note_name().pitch_class() + semitones);
+                SpelledNote::from(note)
+            })
+        })
+        .collect()
+}
+
+// same as `spell_degrees`, but letters may repeat - `E#`, `B#`, `Fb`, `Cb` and double accidentals
+// take their plainer enharmonic (ex: `D` half-whole -> `D`, `Eb`, `F`, `F#` rather than `E#`)
+pub(crate) fn spell_degrees_plainly(
+    root: SpelledNote,
+    degrees: &[(usize, usize)],
+) -> Vec<SpelledNote> {
+    spell_degrees(root, degrees)
+        .into_iter()
+        .enumerate()
+        // (Note: the root keeps its spelling, ex: `Cb` whole tone still starts on `Cb`)
+        .map(|(index, note)| match index {
+            0 => note,
+            _ => plainer_spelling(note),
+        })
+        .collect()
+}
+
+// picks the spelling of `root` that reads with the fewest accidentals across all `degrees`
+// (ex: `Db` major over `C#` major, `C#` minor over `Db` minor - ties lean to flats)
+pub(crate) fn fewest_accidentals_root(root: &NoteName, degrees: &[(usize, usize)]) -> SpelledNote {
+    fewest_accidentals_by(root, |spelled| spell_degrees(spelled, degrees))
+}
+
+// `fewest_accidentals_root` with each candidate root spelled out by `spell`
+pub(crate) fn fewest_accidentals_by(
+    root: &NoteName,
+    spell: impl Fn(SpelledNote) -> Vec<SpelledNote>,
+) -> SpelledNote {
+    // natural roots keep their letter (ex: `C` never becomes `B#`), black keys try both
+    let root_accidentals = if SpelledNote::from(*root).accidental() == Accidental::Natural {
+        0
+    } else {
+        1
+    };
+
+    SpelledNote::spellings(root)
+        .into_iter()
+        .filter(|spelled| spelled.accidental().semitones().abs() == root_accidentals)
+        .min_by_key(|spelled| {
+            let notes = spell(*spelled);
+            let doubles = notes
+                .iter()
+                .filter(|note| note.accidental().semitones().abs() == 2)
+                .count();
+            let accidentals: i32 = notes
+                .iter()
+                .map(|note| note.accidental().semitones().abs())
+                .sum();
+
+            (
+                doubles,
+                accidentals,
+                spelled.accidental() == Accidental::Sharp,
+            )
+        })
+        .unwrap_or_else(|| SpelledNote::from(*root))
+}
+
+// `E#`, `B#`, `Fb`, `Cb` and double accidentals as a natural, otherwise a single accidental in
+// the same direction (ex: `E#` -> `F`, `Abb` -> `G`, `Cbb` -> `Bb`) - other notes stay as they are
+fn plainer_spelling(note: SpelledNote) -> SpelledNote {
+    let is_awkward = |note: &SpelledNote| {
+        note.accidental().semitones().abs() == 2
+            || matches!(
+                (note.letter(), note.accidental()),
+                (Letter::E | Letter::B, Accidental::Sharp)
+                    | (Letter::F | Letter::C, Accidental::Flat)
+            )
+    };
+    if !is_awkward(&note) {
+        return note;
+    }
+
+    let direction = note.accidental().semitones().signum();
+    SpelledNote::spellings(&note.note_name())
+        .into_iter()
+        .filter(|spelled| !is_awkward(spelled))
+        .min_by_key(|spelled| {
+            let semitones = spelled.accidental().semitones();
+            (semitones.abs(), semitones.signum() != direction)
+        })
+        .unwrap_or(note)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SlashChord, SpelledChord};
+
+    fn note(text: &str) -> SpelledNote {
+        SpelledNote::try_from(text).expect("should parse the spelled note")
+    }
+
+    fn key(tonic: &str, quality: ScaleQuality) -> Key {
+        Key::new(note(tonic), quality)
+    }
+
+    fn spelled(notes: &[SpelledNote]) -> Vec<String> {
+        notes.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn keys_spell_every_letter_once() {
+        let b_major = key("B", ScaleQuality::Maj);
+        assert_eq!(
+            spelled(&b_major.scale_notes()),
+            ["B", "C#", "D#", "E", "F#", "G#", "A#"]
+        );
+
+        let eb_minor = key("Eb", ScaleQuality::Min);
+        assert_eq!(
+            spelled(&eb_minor.scale_notes()),
+            ["Eb", "F", "Gb", "Ab", "Bb", "Cb", "Db"]
+        );
+
+        // double accidentals where the letters call for them
+        let g_sharp_harmonic = key("G#", ScaleQuality::HarmMin);
+        assert_eq!(
+            spelled(&g_sharp_harmonic.scale_notes()),
+            ["G#", "A#", "B", "C#", "D#", "E", "F##"]
+        );
+    }
+
+    #[test]
+    fn chromatic_notes_follow_the_key_signature() {
+        let black_keys = [
+            NoteName::CSharpDFlat,
+            NoteName::DSharpEFlat,
+            NoteName::FSharpGFlat,
+            NoteName::GSharpAFlat,
+            NoteName::ASharpBFlat,
+        ];
+        let spell_in = |key: Key| {
+            black_keys
+                .iter()
+                .map(|note| key.spell_note(note).to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            spell_in(key("D", ScaleQuality::Maj)),
+            ["C#", "D#", "F#", "G#", "A#"]
+        );
+        assert_eq!(
+            spell_in(key("F", ScaleQuality::Maj)),
+            ["Db", "Eb", "Gb", "Ab", "Bb"]
+        );
+        // natural notes never take an accidental, even outside of the key
+        assert_eq!(
+            key("Db", ScaleQuality::Maj).spell_note(&NoteName::E),
+            note("E")
+        );
+    }
+
+    #[test]
+    fn chords_take_the_key_spelling_of_their_root() {
+        let d_sharp_min7 = ChordName::try_from("D#m7").expect("should parse the chord symbol");
+
+        let b_major = key("B", ScaleQuality::Maj);
+        assert_eq!(
+            spelled(&d_sharp_min7.spell(Some(&b_major)).expect("should spell")),
+            ["D#", "F#", "C#"]
+        );
+        assert_eq!(d_sharp_min7.spelled_symbol(Some(&b_major)), "D#min7");
+
+        let gb_major = key("Gb", ScaleQuality::Maj);
+        assert_eq!(d_sharp_min7.spelled_symbol(Some(&gb_major)), "Ebmin7");
+    }
+
+    #[test]
+    fn written_flat_roots_keep_their_letter() {
+        // (written symbol, spelled symbol, shell chord tones)
+        let flat_roots = [
+            ("Bbm7b5", "Bbmin7b5", ["Bb", "Db", "Fb", "Ab"].as_slice()),
+            ("Gb7", "Gb7", &["Gb", "Bb", "Fb"]),
+            ("Abm", "Abmin", &["Ab", "Cb", "Eb"]),
+            ("Dbm7", "Dbmin7", &["Db", "Fb", "Cb"]),
+            ("Ebm7b5", "Ebmin7b5", &["Eb", "Gb", "Bbb", "Db"]),
+            ("Gb7/Bb", "Gb7/Bb", &["Gb", "Bb", "Fb"]),
+        ];
+        let e_major = key("E", ScaleQuality::Maj);
+
+        for (symbol, expected, tones) in flat_roots {
+            let chord = SpelledChord::try_from(symbol).expect("should parse the chord symbol");
+
+            assert_eq!(chord.spelled_symbol(None), expected);
+            // a sharp key doesn't respell what was written
+            assert_eq!(chord.spelled_symbol(Some(&e_major)), expected);
+            assert_eq!(spelled(&chord.spell(None).expect("should spell")), tones);
+        }
+
+        // the pitch alone still reads with the fewest accidentals
+        let chord_name = ChordName::try_from("Gb7").expect("should parse the chord symbol");
+        assert_eq!(chord_name.spelled_symbol(None), "F#7");
+    }
+
+    #[test]
+    fn unwritten_letters_fall_back_to_the_key() {
+        // both names of the root written - the key decides
+        let chord = SpelledChord::try_from("C#/Db7").expect("should parse the chord symbol");
+        assert_eq!(
+            chord.spelled_symbol(Some(&key("A", ScaleQuality::Maj))),
+            "C#7"
+        );
+        assert_eq!(
+            chord.spelled_symbol(Some(&key("Ab", ScaleQuality::Maj))),
+            "Db7"
+        );
+
+        // slash basses count their letter from the written root
+        let chord = SpelledChord::new(
+            SlashChord::try_from("Ebm7/Gb").expect("should parse the slash chord"),
+            &note("Eb"),
+        )
+        .expect("should take a matching root");
+        assert_eq!(chord.bass(None), note("Gb"));
+        assert!(SpelledChord::new(chord.chord(), &note("D#")).is_some());
+        assert!(SpelledChord::new(chord.chord(), &note("E")).is_none());
+    }
+
+    #[test]
+    fn degrees_past_double_accidentals_fall_back() {
+        // a major third above `D##` would be `F###` - the default `G#/Ab` spelling steps in
+        assert_eq!(
+            spelled(&spell_degrees(note("D##"), &[(1, 0), (3, 4)])),
+            ["D##", "Ab"]
+        );
+    }
+}
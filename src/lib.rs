@@ -1,17 +1,43 @@
+mod analysis;
 mod baux;
+mod chord_styles;
+mod chord_symbols;
 mod chords;
 mod data_sets;
+mod dictionaries;
+mod edo;
+mod identify;
+mod intervals;
+mod keys;
 mod notes;
+mod progressions;
 mod scales;
+mod spellings;
+mod transpose;
+mod tunings;
 mod voicings;
 
 mod scales_generator;
 
-pub use crate::baux::music_theory_baux;
-pub use crate::chords::{ChordName, ChordQuality, ChordSpelling, ChordTone, ChordToneDegree};
-pub use crate::notes::{NoteName, NoteOct};
-pub use data_sets::generate_music_data;
-pub use scales::{ScaleDegree, ScaleName, ScaleQuality, ScaleSpelling};
+pub use crate::analysis::RomanNumeral;
+pub use crate::baux::{music_theory_baux, MusicTheoryBaux, MusicTheoryBauxBuilder};
+pub use crate::chord_styles::ChordSymbolStyle;
+pub use crate::chords::{
+    AlteredBase, ChordName, ChordQuality, ChordSpelling, ChordTone, ChordToneDegree, SlashChord,
+    SpelledChord, SpellingForm, Tensions,
+};
+pub use crate::notes::{MidiConvention, NoteName, NoteOct, CONCERT_PITCH};
+pub use data_sets::{generate_music_data, MusicData};
+pub use dictionaries::{ChordDictionary, DictionaryError, ScaleDictionary};
+pub use edo::{Edo, EdoChordSpelling, EdoInterval, EdoNote, EdoVoicing};
+pub use identify::{identify_chord, ChordCandidate};
+pub use intervals::{Interval, IntervalQuality};
+pub use keys::Key;
+pub use progressions::{parse_nashville_numbers, parse_roman_numerals};
+pub use scales::{ScaleDegree, ScaleName, ScaleQuality, ScaleSpelling, ScaleSteps};
+pub use spellings::{Accidental, Letter, SpelledNote};
+pub use transpose::Transpose;
+pub use tunings::Tuning;
 pub use voicings::{from_input, ChordVoicing};
 
 pub use scales_generator::*;
@@ -2,7 +2,6 @@
 use music_baux::{
     from_input, music_theory_baux, ChordName, ChordQuality, ChordVoicing, NoteName, NoteOct,
 };
-use music_baux::{ScaleDegree, ScaleName, ScaleQuality};
 use std::time::Instant;
 
 fn main() {
@@ -161,13 +160,6 @@ fn main() {
 
     let mut curr_voicing = first_chord;
 
-    let chord_progression = [
-        ChordName::A(ChordQuality::MajSev),
-        ChordName::CSharpDFlat(ChordQuality::MinSev),
-        ChordName::B(ChordQuality::MajSev),
-        ChordName::E(ChordQuality::MajSev),
-    ];
-
     let chord_progression = [
         ChordName::A(ChordQuality::MajSev),
         ChordName::CSharpDFlat(ChordQuality::MinSev),
@@ -1,4 +1,8 @@
 use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+// standard `A4` reference pitch (Hz)
+pub const CONCERT_PITCH: f64 = 440.0;
 
 #[derive(Debug, thiserror::Error)]
 #[error("Error trying in conversion of note name `{0}` to `NoteName` enum variant (Note: expects capital chars for note name value)")]
@@ -20,6 +24,44 @@ pub enum NoteName {
     B,
 }
 
+impl NoteName {
+    // semitones above `C` (`C = 0, C#/Db = 1, ... B = 11`)
+    pub fn pitch_class(&self) -> usize {
+        match self {
+            NoteName::C => 0,
+            NoteName::CSharpDFlat => 1,
+            NoteName::D => 2,
+            NoteName::DSharpEFlat => 3,
+            NoteName::E => 4,
+            NoteName::F => 5,
+            NoteName::FSharpGFlat => 6,
+            NoteName::G => 7,
+            NoteName::GSharpAFlat => 8,
+            NoteName::A => 9,
+            NoteName::ASharpBFlat => 10,
+            NoteName::B => 11,
+        }
+    }
+
+    // Note: wraps values outside of `0..12` back into a single octave
+    pub fn from_pitch_class(pitch_class: usize) -> Self {
+        match pitch_class % 12 {
+            0 => NoteName::C,
+            1 => NoteName::CSharpDFlat,
+            2 => NoteName::D,
+            3 => NoteName::DSharpEFlat,
+            4 => NoteName::E,
+            5 => NoteName::F,
+            6 => NoteName::FSharpGFlat,
+            7 => NoteName::G,
+            8 => NoteName::GSharpAFlat,
+            9 => NoteName::A,
+            10 => NoteName::ASharpBFlat,
+            _ => NoteName::B,
+        }
+    }
+}
+
 impl Display for NoteName {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -62,27 +104,54 @@ impl TryFrom<&str> for NoteName {
 }
 
 #[derive(Debug, thiserror::Error)]
-#[error("Error trying in conversion of note + octave name `{0}` to `NoteOct` enum variant (Note: for &str conversion - expects capital name + oct values ex: `C1` or `C#/Db4` or `Eb5`)")]
+#[error("Error trying in conversion of note + octave name `{0}` to `NoteOct` enum variant (Note: for &str conversion - expects capital name + oct values from `-2` to `9` ex: `C-1` or `C#/Db4` or `Eb5`)")]
 pub struct NoteOctError(String);
 
+#[derive(Debug, thiserror::Error)]
+#[error("Error trying in conversion of `{0}` to/from a MIDI note number (Note: MIDI note numbers range from `0` to `127`, `NoteOct` octaves range from `-2` to `9`)")]
+pub struct MidiNoteError(String);
+
+// which octave MIDI note `60` (middle C) belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MidiConvention {
+    // `C4 = 60` (scientific pitch notation)
+    #[default]
+    MiddleC4,
+    // `C3 = 60` (Yamaha and various DAWs)
+    MiddleC3,
+}
+
+impl MidiConvention {
+    // octave of MIDI note `0` (`C-1` or `C-2`)
+    fn lowest_octave(&self) -> i32 {
+        match self {
+            MidiConvention::MiddleC4 => -1,
+            MidiConvention::MiddleC3 => -2,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NoteOct {
-    C(usize),
-    CSharpDFlat(usize),
-    D(usize),
-    DSharpEFlat(usize),
-    E(usize),
-    F(usize),
-    FSharpGFlat(usize),
-    G(usize),
-    GSharpAFlat(usize),
-    A(usize),
-    ASharpBFlat(usize),
-    B(usize),
+    C(i32),
+    CSharpDFlat(i32),
+    D(i32),
+    DSharpEFlat(i32),
+    E(i32),
+    F(i32),
+    FSharpGFlat(i32),
+    G(i32),
+    GSharpAFlat(i32),
+    A(i32),
+    ASharpBFlat(i32),
+    B(i32),
 }
 
 impl NoteOct {
-    pub fn from_note(note_name: &NoteName, oct: usize) -> Self {
+    // supported octaves (`C-2` through `B9`) - every MIDI note under either `MidiConvention`
+    pub const OCTAVES: RangeInclusive<i32> = -2..=9;
+
+    pub fn from_note(note_name: &NoteName, oct: i32) -> Self {
         match note_name {
             NoteName::C => NoteOct::C(oct),
             NoteName::CSharpDFlat => NoteOct::CSharpDFlat(oct),
@@ -99,6 +168,78 @@ impl NoteOct {
         }
     }
 
+    pub fn octave(&self) -> i32 {
+        match self {
+            NoteOct::C(oct) => *oct,
+            NoteOct::CSharpDFlat(oct) => *oct,
+            NoteOct::D(oct) => *oct,
+            NoteOct::DSharpEFlat(oct) => *oct,
+            NoteOct::E(oct) => *oct,
+            NoteOct::F(oct) => *oct,
+            NoteOct::FSharpGFlat(oct) => *oct,
+            NoteOct::G(oct) => *oct,
+            NoteOct::GSharpAFlat(oct) => *oct,
+            NoteOct::A(oct) => *oct,
+            NoteOct::ASharpBFlat(oct) => *oct,
+            NoteOct::B(oct) => *oct,
+        }
+    }
+
+    pub fn is_supported(&self) -> bool {
+        NoteOct::OCTAVES.contains(&self.octave())
+    }
+
+    // signed semitones above `C-2` - for ordering and distances, defined for any octave
+    // (ex: `C-3` -> `-12`)
+    pub(crate) fn semitones(&self) -> i32 {
+        (self.octave() - NoteOct::OCTAVES.start()) * 12 + self.note_name().pitch_class() as i32
+    }
+
+    // semitones above the lowest supported note `C-2` - `None` outside of `OCTAVES`
+    pub(crate) fn semitone_index(&self) -> Option<usize> {
+        self.is_supported().then(|| self.semitones() as usize)
+    }
+
+    // Note: `None` past the highest supported note `B9`
+    pub(crate) fn from_semitone_index(index: usize) -> Option<Self> {
+        let note = NoteOct::from_note(
+            &NoteName::from_pitch_class(index % 12),
+            (index / 12) as i32 + NoteOct::OCTAVES.start(),
+        );
+
+        note.is_supported().then_some(note)
+    }
+
+    // equal tempered frequency (Hz) with `A4` tuned to `reference_pitch`
+    // (Note: computed for any octave - notes outside of `OCTAVES` just have no weight or MIDI number)
+    pub fn frequency(&self, reference_pitch: f64) -> f64 {
+        let a4 = NoteOct::A(4).semitones();
+
+        reference_pitch * 2f64.powf((self.semitones() - a4) as f64 / 12.0)
+    }
+
+    pub fn to_midi(&self, convention: MidiConvention) -> Result<u8, MidiNoteError> {
+        let midi = (self.octave() - convention.lowest_octave()) * 12
+            + self.note_name().pitch_class() as i32;
+
+        u8::try_from(midi)
+            .ok()
+            .filter(|midi| *midi <= 127)
+            .ok_or_else(|| MidiNoteError(self.to_string()))
+    }
+
+    // Note: every MIDI note has a `NoteOct` (ex: `0` is `C-1` for `MiddleC4`, `C-2` for `MiddleC3`)
+    pub fn from_midi(midi: u8, convention: MidiConvention) -> Result<Self, MidiNoteError> {
+        if midi > 127 {
+            return Err(MidiNoteError(midi.to_string()));
+        }
+
+        Ok(NoteOct::from_note(
+            &NoteName::from_pitch_class(midi as usize % 12),
+            midi as i32 / 12 + convention.lowest_octave(),
+        ))
+    }
+
     pub fn note_name(&self) -> NoteName {
         match self {
             NoteOct::C(_) => NoteName::C,
@@ -140,112 +281,77 @@ impl TryFrom<&str> for NoteOct {
     type Error = NoteOctError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "C1" => Ok(NoteOct::C(1)),
-            "C#1" | "Db1" | "C#/Db1" => Ok(NoteOct::CSharpDFlat(1)),
-            "D1" => Ok(NoteOct::D(1)),
-            "D#1" | "Eb1" | "D#/Eb1" => Ok(NoteOct::DSharpEFlat(1)),
-            "E1" => Ok(NoteOct::E(1)),
-            "F1" => Ok(NoteOct::F(1)),
-            "F#1" | "Gb1" | "F#/Gb1" => Ok(NoteOct::FSharpGFlat(1)),
-            "G1" => Ok(NoteOct::G(1)),
-            "G#1" | "Ab1" | "G#/Ab1" => Ok(NoteOct::GSharpAFlat(1)),
-            "A1" => Ok(NoteOct::A(1)),
-            "A#1" | "Bb1" | "A#/Bb1" => Ok(NoteOct::ASharpBFlat(1)),
-            "B1" => Ok(NoteOct::B(1)),
-
-            "C2" => Ok(NoteOct::C(2)),
-            "C#2" | "Db2" | "C#/Db2" => Ok(NoteOct::CSharpDFlat(2)),
-            "D2" => Ok(NoteOct::D(2)),
-            "D#2" | "Eb2" | "D#/Eb2" => Ok(NoteOct::DSharpEFlat(2)),
-            "E2" => Ok(NoteOct::E(2)),
-            "F2" => Ok(NoteOct::F(2)),
-            "F#2" | "Gb2" | "F#/Gb2" => Ok(NoteOct::FSharpGFlat(2)),
-            "G2" => Ok(NoteOct::G(2)),
-            "G#2" | "Ab2" | "G#/Ab2" => Ok(NoteOct::GSharpAFlat(2)),
-            "A2" => Ok(NoteOct::A(2)),
-            "A#2" | "Bb2" | "A#/Bb2" => Ok(NoteOct::ASharpBFlat(2)),
-            "B2" => Ok(NoteOct::B(2)),
-
-            "C3" => Ok(NoteOct::C(3)),
-            "C#3" | "Db3" | "C#/Db3" => Ok(NoteOct::CSharpDFlat(3)),
-            "D3" => Ok(NoteOct::D(3)),
-            "D#3" | "Eb3" | "D#/Eb3" => Ok(NoteOct::DSharpEFlat(3)),
-            "E3" => Ok(NoteOct::E(3)),
-            "F3" => Ok(NoteOct::F(3)),
-            "F#3" | "Gb3" | "F#/Gb3" => Ok(NoteOct::FSharpGFlat(3)),
-            "G3" => Ok(NoteOct::G(3)),
-            "G#3" | "Ab3" | "G#/Ab3" => Ok(NoteOct::GSharpAFlat(3)),
-            "A3" => Ok(NoteOct::A(3)),
-            "A#3" | "Bb3" | "A#/Bb3" => Ok(NoteOct::ASharpBFlat(3)),
-            "B3" => Ok(NoteOct::B(3)),
-
-            "C4" => Ok(NoteOct::C(4)),
-            "C#4" | "Db4" | "C#/Db4" => Ok(NoteOct::CSharpDFlat(4)),
-            "D4" => Ok(NoteOct::D(4)),
-            "D#4" | "Eb4" | "D#/Eb4" => Ok(NoteOct::DSharpEFlat(4)),
-            "E4" => Ok(NoteOct::E(4)),
-            "F4" => Ok(NoteOct::F(4)),
-            "F#4" | "Gb4" | "F#/Gb4" => Ok(NoteOct::FSharpGFlat(4)),
-            "G4" => Ok(NoteOct::G(4)),
-            "G#4" | "Ab4" | "G#/Ab4" => Ok(NoteOct::GSharpAFlat(4)),
-            "A4" => Ok(NoteOct::A(4)),
-            "A#4" | "Bb4" | "A#/Bb4" => Ok(NoteOct::ASharpBFlat(4)),
-            "B4" => Ok(NoteOct::B(4)),
-
-            "C5" => Ok(NoteOct::C(5)),
-            "C#5" | "Db5" | "C#/Db5" => Ok(NoteOct::CSharpDFlat(5)),
-            "D5" => Ok(NoteOct::D(5)),
-            "D#5" | "Eb5" | "D#/Eb5" => Ok(NoteOct::DSharpEFlat(5)),
-            "E5" => Ok(NoteOct::E(5)),
-            "F5" => Ok(NoteOct::F(5)),
-            "F#5" | "Gb5" | "F#/Gb5" => Ok(NoteOct::FSharpGFlat(5)),
-            "G5" => Ok(NoteOct::G(5)),
-            "G#5" | "Ab5" | "G#/Ab5" => Ok(NoteOct::GSharpAFlat(5)),
-            "A5" => Ok(NoteOct::A(5)),
-            "A#5" | "Bb5" | "A#/Bb5" => Ok(NoteOct::ASharpBFlat(5)),
-            "B5" => Ok(NoteOct::B(5)),
-
-            "C6" => Ok(NoteOct::C(6)),
-            "C#6" | "Db6" | "C#/Db6" => Ok(NoteOct::CSharpDFlat(6)),
-            "D6" => Ok(NoteOct::D(6)),
-            "D#6" | "Eb6" | "D#/Eb6" => Ok(NoteOct::DSharpEFlat(6)),
-            "E6" => Ok(NoteOct::E(6)),
-            "F6" => Ok(NoteOct::F(6)),
-            "F#6" | "Gb6" | "F#/Gb6" => Ok(NoteOct::FSharpGFlat(6)),
-            "G6" => Ok(NoteOct::G(6)),
-            "G#6" | "Ab6" | "G#/Ab6" => Ok(NoteOct::GSharpAFlat(6)),
-            "A6" => Ok(NoteOct::A(6)),
-            "A#6" | "Bb6" | "A#/Bb6" => Ok(NoteOct::ASharpBFlat(6)),
-            "B6" => Ok(NoteOct::B(6)),
-
-            "C7" => Ok(NoteOct::C(7)),
-            "C#7" | "Db7" | "C#/Db7" => Ok(NoteOct::CSharpDFlat(7)),
-            "D7" => Ok(NoteOct::D(7)),
-            "D#7" | "Eb7" | "D#/Eb7" => Ok(NoteOct::DSharpEFlat(7)),
-            "E7" => Ok(NoteOct::E(7)),
-            "F7" => Ok(NoteOct::F(7)),
-            "F#7" | "Gb7" | "F#/Gb7" => Ok(NoteOct::FSharpGFlat(7)),
-            "G7" => Ok(NoteOct::G(7)),
-            "G#7" | "Ab7" | "G#/Ab7" => Ok(NoteOct::GSharpAFlat(7)),
-            "A7" => Ok(NoteOct::A(7)),
-            "A#7" | "Bb7" | "A#/Bb7" => Ok(NoteOct::ASharpBFlat(7)),
-            "B7" => Ok(NoteOct::B(7)),
-
-            "C8" => Ok(NoteOct::C(8)),
-            "C#8" | "Db8" | "C#/Db8" => Ok(NoteOct::CSharpDFlat(8)),
-            "D8" => Ok(NoteOct::D(8)),
-            "D#8" | "Eb8" | "D#/Eb8" => Ok(NoteOct::DSharpEFlat(8)),
-            "E8" => Ok(NoteOct::E(8)),
-            "F8" => Ok(NoteOct::F(8)),
-            "F#8" | "Gb8" | "F#/Gb8" => Ok(NoteOct::FSharpGFlat(8)),
-            "G8" => Ok(NoteOct::G(8)),
-            "G#8" | "Ab8" | "G#/Ab8" => Ok(NoteOct::GSharpAFlat(8)),
-            "A8" => Ok(NoteOct::A(8)),
-            "A#8" | "Bb8" | "A#/Bb8" => Ok(NoteOct::ASharpBFlat(8)),
-            "B8" => Ok(NoteOct::B(8)),
-
-            unknown => Err(NoteOctError(unknown.to_string())),
+        // split the trailing octave from the note name (ex: `C#/Db4` -> `C#/Db` + `4`, `C-1` -> `C` + `-1`)
+        let split_at = value
+            .find(|c: char| c.is_ascii_digit() || c == '-')
+            .ok_or_else(|| NoteOctError(value.to_string()))?;
+        let (note, oct) = value.split_at(split_at);
+
+        let note_name = NoteName::try_from(note).map_err(|_| NoteOctError(value.to_string()))?;
+        let oct = oct
+            .parse::<i32>()
+            .ok()
+            .filter(|oct| NoteOct::OCTAVES.contains(oct))
+            .ok_or_else(|| NoteOctError(value.to_string()))?;
+
+        Ok(NoteOct::from_note(&note_name, oct))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn midi_round_trips_for_both_conventions() {
+        for convention in [MidiConvention::MiddleC4, MidiConvention::MiddleC3] {
+            for midi in 0..=127 {
+                let note = NoteOct::from_midi(midi, convention).unwrap();
+
+                assert!(NoteOct::OCTAVES.contains(&note.octave()), "`{}`", note);
+                assert_eq!(note.to_midi(convention).unwrap(), midi, "`{}`", note);
+                assert_eq!(NoteOct::try_from(note.to_string().as_str()).unwrap(), note);
+            }
+            assert!(NoteOct::from_midi(128, convention).is_err());
         }
+
+        assert_eq!(
+            NoteOct::from_midi(0, MidiConvention::MiddleC4).unwrap(),
+            NoteOct::C(-1)
+        );
+        assert_eq!(
+            NoteOct::from_midi(127, MidiConvention::MiddleC4).unwrap(),
+            NoteOct::G(9)
+        );
+        assert_eq!(
+            NoteOct::from_midi(0, MidiConvention::MiddleC3).unwrap(),
+            NoteOct::C(-2)
+        );
+        assert_eq!(
+            NoteOct::from_midi(60, MidiConvention::MiddleC3).unwrap(),
+            NoteOct::C(3)
+        );
+        assert!(NoteOct::GSharpAFlat(9)
+            .to_midi(MidiConvention::MiddleC4)
+            .is_err());
+    }
+
+    #[test]
+    fn octaves_outside_the_range_have_no_index() {
+        for note in [NoteOct::C(-3), NoteOct::B(-3), NoteOct::C(10)] {
+            assert!(!note.is_supported(), "`{}`", note);
+            assert_eq!(note.semitone_index(), None, "`{}`", note);
+            assert!(note.frequency(440.0).is_finite(), "`{}`", note);
+            assert!(NoteOct::try_from(note.to_string().as_str()).is_err());
+        }
+
+        assert_eq!(NoteOct::C(-2).semitone_index(), Some(0));
+        assert_eq!(NoteOct::B(9).semitone_index(), Some(143));
+        assert_eq!(NoteOct::from_semitone_index(143), Some(NoteOct::B(9)));
+        assert_eq!(NoteOct::from_semitone_index(144), None);
+
+        // (Note: an octave below `C-2` still halves the frequency)
+        let c_minus_2 = NoteOct::C(-2).frequency(440.0);
+        assert!((NoteOct::C(-3).frequency(440.0) - c_minus_2 / 2.0).abs() < 1e-9);
     }
 }
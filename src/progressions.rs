@@ -0,0 +1,385 @@
+use crate::analysis::{diatonic_degree, is_seventh, is_triad};
+use crate::{
+    music_theory_baux, ChordName, ChordQuality, Key, NoteName, RomanNumeral, ScaleQuality,
+    SlashChord, SpelledNote, SpellingForm,
+};
+
+#[derive(Debug, thiserror::Error)]
+#[error("ERROR: failed to read `{input}` as a chord in `{key}`: {reason} (Note: Roman numerals look like `ii7`, `V65`, `bVII`, `V7/vi`, Nashville numbers like `2-7`, `5`, `b7`, `1/3` - chords are separated by spaces, `|` or `,`)")]
+pub struct ProgressionError {
+    input: String,
+    key: String,
+    reason: String,
+}
+
+impl ProgressionError {
+    fn new(input: &str, key: &Key, reason: String) -> Self {
+        Self {
+            input: input.to_string(),
+            key: key.to_string(),
+            reason,
+        }
+    }
+
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+// === ROMAN NUMERAL GRAMMAR === //
+//
+// numeral   = chord {"/" chord}                  (ex: `V7/V`, `V/V/vi` - each tonicizes the next)
+// chord     = [accidental] roman [quality] [figures]
+// accidental = "b" | "bb" | "♭" | "#" | "♯" | "♮" (off the major scale's degree, ex: `bVII`)
+// roman     = "I" .. "VII" | "i" .. "vii"        (lowercase for a minor third)
+// quality   = "°" | "o" | "ø" | "+" | a chord symbol quality (ex: `7`, `maj7`, `9`, `add6`, `7b9`)
+// figures   = "6" | "64" (triads) | "65" | "43" | "42" | "2" (sevenths)
+//
+// (Note: unaltered numerals stay on the key's own degree - in `C` minor `VII` and `bVII` are both
+// `Bb`, while `♮VII` is `B`)
+//
+// === NASHVILLE NUMBER GRAMMAR === //
+//
+// number    = [accidental] degree [quality] ["/" [accidental] degree]   (ex: `2-7`, `b7`, `1/3`)
+// accidental = as for Roman numerals           (ex: `b7` -> `Bb` in `C` major and minor)
+// degree    = "1" .. "7"                        (plain numbers are major chords)
+// quality   = a chord symbol quality             (ex: `-`, `m7`, `7`, `maj7`, `sus4`, `°`)
+
+// `(figures, seventh chord, inversion)` - longest figures first
+const FIGURES: [(&str, bool, usize); 6] = [
+    ("65", true, 1),
+    ("43", true, 2),
+    ("42", true, 3),
+    ("64", false, 2),
+    ("2", true, 3),
+    ("6", false, 1),
+];
+
+const NUMERALS: [(&str, usize); 7] = [
+    ("VII", 7),
+    ("III", 3),
+    ("II", 2),
+    ("IV", 4),
+    ("VI", 6),
+    ("V", 5),
+    ("I", 1),
+];
+
+impl RomanNumeral {
+    // the chord of a single numeral, analyzed back in `key` (ex: `V7/vi` -> `E7` in `C` major)
+    pub fn parse(key: &Key, numeral: &str) -> Result<Self, ProgressionError> {
+        let chord = parse_numeral(key, numeral)
+            .map_err(|reason| ProgressionError::new(numeral, key, reason))?;
+
+        Ok(RomanNumeral::analyze(key, &chord))
+    }
+}
+
+// chords of a Roman numeral progression, ready for `ChordVoicing::voice_lead_slash`
+// (ex: `ii7 V7 Imaj7` -> `Dmin7`, `G7`, `Cmaj7` in `C` major, `V65/V` -> `D7/F#`)
+// (Note: minor keys raise their sixth / seventh where it makes the chord diatonic, ex: `vii°7`)
+pub fn parse_roman_numerals(key: &Key, text: &str) -> Result<Vec<SlashChord>, ProgressionError> {
+    tokens(text)
+        .map(|numeral| {
+            parse_numeral(key, numeral)
+                .map_err(|reason| ProgressionError::new(numeral, key, reason))
+        })
+        .collect()
+}
+
+// chords of a Nashville number progression (ex: `2-7 5 1` -> `Dmin7`, `G`, `C` in `C` major)
+// (Note: a slash number is a bass degree of the key, ex: `1/3` -> `C/E`)
+pub fn parse_nashville_numbers(key: &Key, text: &str) -> Result<Vec<SlashChord>, ProgressionError> {
+    tokens(text)
+        .map(|number| {
+            parse_number(key, number).map_err(|reason| ProgressionError::new(number, key, reason))
+        })
+        .collect()
+}
+
+fn tokens(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| c.is_whitespace() || c == '|' || c == ',')
+        .filter(|token| !token.is_empty())
+}
+
+fn parse_numeral(key: &Key, numeral: &str) -> Result<SlashChord, String> {
+    // `6/9` chords would read as a secondary function
+    let numeral = numeral.replace("6/9", "69");
+
+    let Some((chord, target)) = numeral.split_once('/') else {
+        return parse_numeral_chord(key, &numeral);
+    };
+
+    // the secondary chord reads in the key of its target (ex: `V7/vi` -> `V7` in `A` minor)
+    let target = parse_numeral(key, target)?.chord();
+    let quality = match target.get_quality() {
+        ChordQuality::Maj => ScaleQuality::Maj,
+        ChordQuality::Min => ScaleQuality::Min,
+        _ => {
+            return Err(format!(
+                "only major / minor triads can be tonicized, found `{}`",
+                target
+            ))
+        }
+    };
+    let target_key = Key::new(key.spell_note(&target.get_root()), quality);
+
+    parse_numeral_chord(&target_key, chord)
+}
+
+fn parse_numeral_chord(key: &Key, numeral: &str) -> Result<SlashChord, String> {
+    let (alteration, rest) = split_accidental(numeral);
+
+    let (degree, minor, suffix) = NUMERALS
+        .iter()
+        .find_map(|(roman, degree)| {
+            if let Some(suffix) = rest.strip_prefix(roman) {
+                return Some((*degree, false, suffix));
+            }
+            rest.strip_prefix(roman.to_lowercase().as_str())
+                .map(|suffix| (*degree, true, suffix))
+        })
+        .ok_or_else(|| format!("expected a Roman numeral `I` to `VII`, found `{}`", rest))?;
+
+    let (quality, inversion) = FIGURES
+        .iter()
+        .find_map(|(figures, seventh, inversion)| {
+            let prefix = suffix.strip_suffix(figures)?;
+            // inverted seventh chords drop their `7` (ex: `V65`, `viiø43`)
+            let symbol = match seventh {
+                true => format!("{}7", prefix),
+                false => prefix.to_string(),
+            };
+            numeral_quality(minor, &symbol)
+                .filter(|quality| match seventh {
+                    true => is_seventh(quality),
+                    false => is_triad(quality),
+                })
+                .map(|quality| (quality, *inversion))
+        })
+        .or_else(|| numeral_quality(minor, suffix).map(|quality| (quality, 0)))
+        .ok_or_else(|| format!("unknown chord quality `{}`", suffix))?;
+
+    let roots = degree_roots(key, degree, alteration)
+        .into_iter()
+        .map(|root| ChordName::new(&root, quality))
+        .collect::<Vec<_>>();
+    // minor keys take the first root that keeps the chord diatonic (ex: `vii°` on the raised seventh)
+    let chord = roots
+        .iter()
+        .find(|chord| diatonic_degree(key, chord).is_some())
+        .unwrap_or(&roots[0]);
+
+    let bass = music_theory_baux
+        .get_chord_spelling_form(chord, SpellingForm::Complete)
+        .and_then(|chord_spelling| chord_spelling.spelling().get(inversion).copied())
+        .map_or(chord.get_root(), |chord_tone| *chord_tone.note());
+
+    Ok(SlashChord::new(*chord, &bass))
+}
+
+// numeral case + written quality as a `ChordQuality` (ex: lowercase `7` -> `min7`, `ø` -> `min7b5`)
+fn numeral_quality(minor: bool, symbol: &str) -> Option<ChordQuality> {
+    let symbol = match (minor, symbol) {
+        (_, "°" | "o") => "dim".to_string(),
+        (_, "°7" | "o7") => "dim7".to_string(),
+        (_, "ø" | "ø7") => "m7b5".to_string(),
+        (true, "add6") => "m6".to_string(),
+        (false, "add6") => "6".to_string(),
+        (true, symbol) => format!("m{}", symbol),
+        (false, symbol) => symbol.to_string(),
+    };
+
+    ChordName::try_from(format!("C{}", symbol).as_str())
+        .ok()
+        .map(|chord| chord.get_quality())
+}
+
+fn parse_number(key: &Key, number: &str) -> Result<SlashChord, String> {
+    let (chord, bass) = match number.split_once('/') {
+        Some((chord, bass)) => (chord, Some(bass)),
+        None => (number, None),
+    };
+
+    let (degree, suffix) = split_degree(chord)?;
+    let root = SpelledNote::from(degree_root(key, degree.0, degree.1));
+    let chord = ChordName::try_from(format!("{}{}", root, suffix).as_str())
+        .map_err(|_| format!("unknown chord quality `{}`", suffix))?;
+
+    let bass = match bass {
+        Some(bass) => match split_degree(bass)? {
+            ((degree, alteration), "") => degree_root(key, degree, alteration),
+            (_, rest) => return Err(format!("unexpected `{}` after the bass number", rest)),
+        },
+        None => chord.get_root(),
+    };
+
+    Ok(SlashChord::new(chord, &bass))
+}
+
+// `((degree, alteration), rest)` of a Nashville number (ex: `b7maj7` -> `((7, Some(-1)), "maj7")`)
+fn split_degree(number: &str) -> Result<((usize, Option<i32>), &str), String> {
+    let (alteration, rest) = split_accidental(number);
+
+    match rest.chars().next().and_then(|c| c.to_digit(10)) {
+        Some(degree @ 1..=7) => Ok(((degree as usize, alteration), &rest[1..])),
+        _ => Err(format!(
+            "expected a degree number `1` to `7`, found `{}`",
+            rest
+        )),
+    }
+}
+
+// semitones off the major scale's degree - `None` without an accidental
+fn split_accidental(token: &str) -> (Option<i32>, &str) {
+    let accidentals = [
+        ("bb", -2),
+        ("b", -1),
+        ("♭", -1),
+        ("#", 1),
+        ("♯", 1),
+        ("♮", 0),
+    ];
+    for (accidental, semitones) in accidentals {
+        if let Some(rest) = token.strip_prefix(accidental) {
+            return (Some(semitones), rest);
+        }
+    }
+
+    (None, token)
+}
+
+// note on `degree` of the key, or `alteration` semitones off the major scale's `degree`
+// (ex: `bVII` -> `Bb` in both `C` major and `C` minor, where the plain `VII` is already `Bb`)
+// (Note: pentatonic keys fall back to the major scale for the degrees they skip)
+fn degree_root(key: &Key, degree: usize, alteration: Option<i32>) -> NoteName {
+    let formula = match alteration {
+        Some(_) => ScaleQuality::Maj.formula(),
+        None => key.quality().formula(),
+    };
+    let semitones = formula
+        .into_iter()
+        .chain(ScaleQuality::Maj.formula())
+        .find(|(number, _)| *number == degree)
+        .map_or(0, |(_, semitones)| semitones);
+    let pitch_class =
+        key.tonic().note_name().pitch_class() as i32 + semitones as i32 + alteration.unwrap_or(0);
+
+    NoteName::from_pitch_class(pitch_class.rem_euclid(12) as usize)
+}
+
+// the key's own root for `degree`, then its raised sixth / seventh in minor keys
+fn degree_roots(key: &Key, degree: usize, alteration: Option<i32>) -> Vec<NoteName> {
+    let root = degree_root(key, degree, alteration);
+
+    match (key.quality(), degree, alteration) {
+        (ScaleQuality::Min, 6 | 7, None) => vec![root, degree_root(key, degree, Some(0))],
+        _ => vec![root],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(quality: ScaleQuality) -> Key {
+        Key::new(SpelledNote::from(NoteName::C), quality)
+    }
+
+    fn chords(symbols: &str) -> Vec<SlashChord> {
+        symbols
+            .split(' ')
+            .map(|symbol| SlashChord::try_from(symbol).expect("should parse the chord symbol"))
+            .collect()
+    }
+
+    fn roman(quality: ScaleQuality, numerals: &str) -> Vec<SlashChord> {
+        parse_roman_numerals(&key(quality), numerals).expect("should parse the numerals")
+    }
+
+    fn nashville(quality: ScaleQuality, numbers: &str) -> Vec<SlashChord> {
+        parse_nashville_numbers(&key(quality), numbers).expect("should parse the numbers")
+    }
+
+    #[test]
+    fn numerals_spell_their_own_quality() {
+        use ScaleQuality::{Maj, Min};
+
+        // case and symbols pick the quality, whatever the key's mode
+        for quality in [Maj, Min] {
+            assert_eq!(roman(quality, "ii7 V7 Imaj7"), chords("Dmin7 G7 Cmaj7"));
+            assert_eq!(roman(quality, "i iv V7"), chords("Cmin Fmin G7"));
+            assert_eq!(roman(quality, "vii°7 #iv°7"), chords("Bdim7 F#dim7"));
+            assert_eq!(roman(quality, "V/V V7/V V65/V"), chords("Dmaj D7 D7/F#"));
+            assert_eq!(
+                roman(quality, "I6 V64 viiø43 V42"),
+                chords("Cmaj/E Gmaj/D Bmin7b5/F G7/F")
+            );
+        }
+    }
+
+    #[test]
+    fn numeral_roots_follow_the_key() {
+        use ScaleQuality::{Maj, Min};
+
+        assert_eq!(roman(Maj, "VII VI III"), chords("Bmaj Amaj Emaj"));
+        assert_eq!(roman(Min, "VII VI III"), chords("Bbmaj Abmaj Ebmaj"));
+        assert_eq!(roman(Maj, "V7/vi"), chords("E7"));
+        assert_eq!(roman(Min, "V7/vi"), chords("Eb7"));
+
+        // explicit accidentals are read off the major scale in either mode
+        assert_eq!(roman(Maj, "bVII bVI bIII"), chords("Bbmaj Abmaj Ebmaj"));
+        assert_eq!(roman(Min, "bVII bVI bIII"), chords("Bbmaj Abmaj Ebmaj"));
+        assert_eq!(roman(Min, "♮VII ♮VI"), chords("Bmaj Amaj"));
+    }
+
+    #[test]
+    fn nashville_numbers_parse_in_major_and_minor() {
+        use ScaleQuality::{Maj, Min};
+
+        assert_eq!(nashville(Maj, "2- 5 1"), chords("Dmin Gmaj Cmaj"));
+        assert_eq!(nashville(Maj, "2-7 5 1maj7"), chords("Dmin7 Gmaj Cmaj7"));
+        assert_eq!(nashville(Min, "b7 4 1"), chords("Bbmaj Fmaj Cmaj"));
+        assert_eq!(nashville(Maj, "6- 4 1/3"), chords("Amin Fmaj Cmaj/E"));
+        assert_eq!(nashville(Min, "6- 4 1/3"), chords("Abmin Fmaj Cmaj/Eb"));
+    }
+
+    #[test]
+    fn numerals_read_back_from_their_chords() {
+        let text = "i iv bVI bVII ♮VI V65/V vii°7 III";
+
+        for key in [key(ScaleQuality::Maj), key(ScaleQuality::Min)] {
+            for numeral in tokens(text) {
+                let parsed = RomanNumeral::parse(&key, numeral).expect("should parse the numeral");
+                let chords = parse_roman_numerals(&key, &parsed.to_string())
+                    .expect("should parse the analysis");
+
+                assert_eq!(chords, [parsed.chord()], "{numeral} in {key}");
+            }
+        }
+    }
+
+    #[test]
+    fn bad_numerals_are_rejected() {
+        // (text, part of the reason)
+        let bad_numerals = [
+            ("X7", "expected a Roman numeral"),
+            ("Vxyz", "unknown chord quality"),
+            ("V/vii°", "only major / minor triads can be tonicized"),
+        ];
+
+        for (text, reason) in bad_numerals {
+            let err = parse_roman_numerals(&key(ScaleQuality::Maj), text)
+                .expect_err("should reject the numeral");
+            assert!(err.reason().contains(reason), "{text}: {}", err.reason());
+        }
+
+        let err = parse_nashville_numbers(&key(ScaleQuality::Maj), "8")
+            .expect_err("should reject the number");
+        assert!(err.reason().contains("expected a degree number"));
+    }
+}
@@ -1,7 +1,10 @@
-use crate::NoteName;
+use crate::keys::{fewest_accidentals_by, spell_degrees, spell_degrees_plainly};
+use crate::transpose::{Transpose, TransposeError};
+use crate::{ChordName, Interval, Key, NoteName, SpelledNote};
+use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ScaleQuality {
     Maj,
     Min,
@@ -9,6 +12,339 @@ pub enum ScaleQuality {
     MelMin,
     MajPent,
     MinPent,
+    // church modes (`Maj` / `Min` are Ionian / Aeolian)
+    Dorian,
+    Phrygian,
+    Lydian,
+    Mixolydian,
+    Locrian,
+    // modes of melodic minor
+    DorianFlatTwo,
+    LydianAug,
+    LydianDom,
+    MixolydianFlatSix,
+    LocrianSharpTwo,
+    Altered,
+    // modes of harmonic minor
+    LocrianSharpSix,
+    IonianSharpFive,
+    DorianSharpFour,
+    PhrygianDom,
+    LydianSharpTwo,
+    Ultralocrian,
+    // symmetric scales
+    WholeTone,
+    HalfWholeDim,
+    WholeHalfDim,
+    Augmented,
+    // blues + bebop
+    Blues,
+    MajBlues,
+    BebopDom,
+    BebopMaj,
+    BebopMin,
+    // heptatonic world scales
+    HungarianMin,
+    HungarianMaj,
+    DoubleHarm,
+    NeapolitanMaj,
+    NeapolitanMin,
+    Persian,
+    Enigmatic,
+    // hexatonic / pentatonic world scales
+    Prometheus,
+    Hirajoshi,
+    InSen,
+    Iwato,
+    Kumoi,
+    Yo,
+    Egyptian,
+    // user-defined steps (ex: `WWHWWWH`) - left out of `all()`
+    Custom(ScaleSteps),
+}
+
+// each family's modes in order of the parent degree they start from (ex: mode `2` of `Maj` is `Dorian`)
+const MODE_FAMILIES: [[ScaleQuality; 7]; 3] = [
+    [
+        ScaleQuality::Maj,
+        ScaleQuality::Dorian,
+        ScaleQuality::Phrygian,
+        ScaleQuality::Lydian,
+        ScaleQuality::Mixolydian,
+        ScaleQuality::Min,
+        ScaleQuality::Locrian,
+    ],
+    [
+        ScaleQuality::MelMin,
+        ScaleQuality::DorianFlatTwo,
+        ScaleQuality::LydianAug,
+        ScaleQuality::LydianDom,
+        ScaleQuality::MixolydianFlatSix,
+        ScaleQuality::LocrianSharpTwo,
+        ScaleQuality::Altered,
+    ],
+    [
+        ScaleQuality::HarmMin,
+        ScaleQuality::LocrianSharpSix,
+        ScaleQuality::IonianSharpFive,
+        ScaleQuality::DorianSharpFour,
+        ScaleQuality::PhrygianDom,
+        ScaleQuality::LydianSharpTwo,
+        ScaleQuality::Ultralocrian,
+    ],
+];
+
+impl ScaleQuality {
+    pub fn all() -> Vec<Self> {
+        vec![
+            ScaleQuality::Maj,
+            ScaleQuality::Min,
+            ScaleQuality::HarmMin,
+            ScaleQuality::MelMin,
+            ScaleQuality::MajPent,
+            ScaleQuality::MinPent,
+            ScaleQuality::Dorian,
+            ScaleQuality::Phrygian,
+            ScaleQuality::Lydian,
+            ScaleQuality::Mixolydian,
+            ScaleQuality::Locrian,
+            ScaleQuality::DorianFlatTwo,
+            ScaleQuality::LydianAug,
+            ScaleQuality::LydianDom,
+            ScaleQuality::MixolydianFlatSix,
+            ScaleQuality::LocrianSharpTwo,
+            ScaleQuality::Altered,
+            ScaleQuality::LocrianSharpSix,
+            ScaleQuality::IonianSharpFive,
+            ScaleQuality::DorianSharpFour,
+            ScaleQuality::PhrygianDom,
+            ScaleQuality::LydianSharpTwo,
+            ScaleQuality::Ultralocrian,
+            ScaleQuality::WholeTone,
+            ScaleQuality::HalfWholeDim,
+            ScaleQuality::WholeHalfDim,
+            ScaleQuality::Augmented,
+            ScaleQuality::Blues,
+            ScaleQuality::MajBlues,
+            ScaleQuality::BebopDom,
+            ScaleQuality::BebopMaj,
+            ScaleQuality::BebopMin,
+            ScaleQuality::HungarianMin,
+            ScaleQuality::HungarianMaj,
+            ScaleQuality::DoubleHarm,
+            ScaleQuality::NeapolitanMaj,
+            ScaleQuality::NeapolitanMin,
+            ScaleQuality::Persian,
+            ScaleQuality::Enigmatic,
+            ScaleQuality::Prometheus,
+            ScaleQuality::Hirajoshi,
+            ScaleQuality::InSen,
+            ScaleQuality::Iwato,
+            ScaleQuality::Kumoi,
+            ScaleQuality::Yo,
+            ScaleQuality::Egyptian,
+        ]
+    }
+
+    // `(degree number, semitones above the root)` for each scale degree
+    pub fn formula(&self) -> Vec<(usize, usize)> {
+        match self {
+            ScaleQuality::Maj => vec![(1, 0), (2, 2), (3, 4), (4, 5), (5, 7), (6, 9), (7, 11)],
+            ScaleQuality::Min => vec![(1, 0), (2, 2), (3, 3), (4, 5), (5, 7), (6, 8), (7, 10)],
+            ScaleQuality::HarmMin => vec![(1, 0), (2, 2), (3, 3), (4, 5), (5, 7), (6, 8), (7, 11)],
+            ScaleQuality::MelMin => vec![(1, 0), (2, 2), (3, 3), (4, 5), (5, 7), (6, 9), (7, 11)],
+            ScaleQuality::MajPent => vec![(1, 0), (2, 2), (3, 4), (5, 7), (6, 9)],
+            ScaleQuality::MinPent => vec![(1, 0), (3, 3), (4, 5), (5, 7), (7, 10)],
+            ScaleQuality::Dorian => vec![(1, 0), (2, 2), (3, 3), (4, 5), (5, 7), (6, 9), (7, 10)],
+            ScaleQuality::Phrygian => vec![(1, 0), (2, 1), (3, 3), (4, 5), (5, 7), (6, 8), (7, 10)],
+            ScaleQuality::Lydian => vec![(1, 0), (2, 2), (3, 4), (4, 6), (5, 7), (6, 9), (7, 11)],
+            ScaleQuality::Mixolydian => {
+                vec![(1, 0), (2, 2), (3, 4), (4, 5), (5, 7), (6, 9), (7, 10)]
+            }
+            ScaleQuality::Locrian => vec![(1, 0), (2, 1), (3, 3), (4, 5), (5, 6), (6, 8), (7, 10)],
+            ScaleQuality::DorianFlatTwo => {
+                vec![(1, 0), (2, 1), (3, 3), (4, 5), (5, 7), (6, 9), (7, 10)]
+            }
+            ScaleQuality::LydianAug => {
+                vec![(1, 0), (2, 2), (3, 4), (4, 6), (5, 8), (6, 9), (7, 11)]
+            }
+            ScaleQuality::LydianDom => {
+                vec![(1, 0), (2, 2), (3, 4), (4, 6), (5, 7), (6, 9), (7, 10)]
+            }
+            ScaleQuality::MixolydianFlatSix => {
+                vec![(1, 0), (2, 2), (3, 4), (4, 5), (5, 7), (6, 8), (7, 10)]
+            }
+            ScaleQuality::LocrianSharpTwo => {
+                vec![(1, 0), (2, 2), (3, 3), (4, 5), (5, 6), (6, 8), (7, 10)]
+            }
+            // Note: spelled with a `b4` (not a `3`) so each letter appears once (ex: `C`, `Db`, `Eb`, `Fb`)
+            ScaleQuality::Altered => vec![(1, 0), (2, 1), (3, 3), (4, 4), (5, 6), (6, 8), (7, 10)],
+            ScaleQuality::LocrianSharpSix => {
+                vec![(1, 0), (2, 1), (3, 3), (4, 5), (5, 6), (6, 9), (7, 10)]
+            }
+            ScaleQuality::IonianSharpFive => {
+                vec![(1, 0), (2, 2), (3, 4), (4, 5), (5, 8), (6, 9), (7, 11)]
+            }
+            ScaleQuality::DorianSharpFour => {
+                vec![(1, 0), (2, 2), (3, 3), (4, 6), (5, 7), (6, 9), (7, 10)]
+            }
+            ScaleQuality::PhrygianDom => {
+                vec![(1, 0), (2, 1), (3, 4), (4, 5), (5, 7), (6, 8), (7, 10)]
+            }
+            ScaleQuality::LydianSharpTwo => {
+                vec![(1, 0), (2, 3), (3, 4), (4, 6), (5, 7), (6, 9), (7, 11)]
+            }
+            ScaleQuality::Ultralocrian => {
+                vec![(1, 0), (2, 1), (3, 3), (4, 4), (5, 6), (6, 8), (7, 9)]
+            }
+            ScaleQuality::WholeTone => vec![(1, 0), (2, 2), (3, 4), (4, 6), (5, 8), (7, 10)],
+            // Note: the `b2` and `#2` share a letter (ex: `C`, `Db`, `D#`, `E`, `F#`, `G`, `A`, `Bb`)
+            ScaleQuality::HalfWholeDim => {
+                vec![
+                    (1, 0),
+                    (2, 1),
+                    (2, 3),
+                    (3, 4),
+                    (4, 6),
+                    (5, 7),
+                    (6, 9),
+                    (7, 10),
+                ]
+            }
+            ScaleQuality::WholeHalfDim => {
+                vec![
+                    (1, 0),
+                    (2, 2),
+                    (3, 3),
+                    (4, 5),
+                    (5, 6),
+                    (6, 8),
+                    (6, 9),
+                    (7, 11),
+                ]
+            }
+            ScaleQuality::Augmented => vec![(1, 0), (2, 3), (3, 4), (5, 7), (5, 8), (7, 11)],
+            ScaleQuality::Blues => vec![(1, 0), (3, 3), (4, 5), (5, 6), (5, 7), (7, 10)],
+            ScaleQuality::MajBlues => vec![(1, 0), (2, 2), (3, 3), (3, 4), (5, 7), (6, 9)],
+            ScaleQuality::BebopDom => {
+                vec![
+                    (1, 0),
+                    (2, 2),
+                    (3, 4),
+                    (4, 5),
+                    (5, 7),
+                    (6, 9),
+                    (7, 10),
+                    (7, 11),
+                ]
+            }
+            ScaleQuality::BebopMaj => {
+                vec![
+                    (1, 0),
+                    (2, 2),
+                    (3, 4),
+                    (4, 5),
+                    (5, 7),
+                    (5, 8),
+                    (6, 9),
+                    (7, 11),
+                ]
+            }
+            ScaleQuality::BebopMin => {
+                vec![
+                    (1, 0),
+                    (2, 2),
+                    (3, 3),
+                    (3, 4),
+                    (4, 5),
+                    (5, 7),
+                    (6, 9),
+                    (7, 10),
+                ]
+            }
+            ScaleQuality::HungarianMin => {
+                vec![(1, 0), (2, 2), (3, 3), (4, 6), (5, 7), (6, 8), (7, 11)]
+            }
+            ScaleQuality::HungarianMaj => {
+                vec![(1, 0), (2, 3), (3, 4), (4, 6), (5, 7), (6, 9), (7, 10)]
+            }
+            ScaleQuality::DoubleHarm => {
+                vec![(1, 0), (2, 1), (3, 4), (4, 5), (5, 7), (6, 8), (7, 11)]
+            }
+            ScaleQuality::NeapolitanMaj => {
+                vec![(1, 0), (2, 1), (3, 3), (4, 5), (5, 7), (6, 9), (7, 11)]
+            }
+            ScaleQuality::NeapolitanMin => {
+                vec![(1, 0), (2, 1), (3, 3), (4, 5), (5, 7), (6, 8), (7, 11)]
+            }
+            ScaleQuality::Persian => vec![(1, 0), (2, 1), (3, 4), (4, 5), (5, 6), (6, 8), (7, 11)],
+            ScaleQuality::Enigmatic => {
+                vec![(1, 0), (2, 1), (3, 4), (4, 6), (5, 8), (6, 10), (7, 11)]
+            }
+            ScaleQuality::Prometheus => vec![(1, 0), (2, 2), (3, 4), (4, 6), (6, 9), (7, 10)],
+            ScaleQuality::Hirajoshi => vec![(1, 0), (2, 2), (3, 3), (5, 7), (6, 8)],
+            ScaleQuality::InSen => vec![(1, 0), (2, 1), (4, 5), (5, 7), (7, 10)],
+            ScaleQuality::Iwato => vec![(1, 0), (2, 1), (4, 5), (5, 6), (7, 10)],
+            ScaleQuality::Kumoi => vec![(1, 0), (2, 2), (3, 3), (5, 7), (6, 9)],
+            ScaleQuality::Yo => vec![(1, 0), (2, 2), (4, 5), (5, 7), (6, 9)],
+            ScaleQuality::Egyptian => vec![(1, 0), (2, 2), (4, 5), (5, 7), (7, 10)],
+            ScaleQuality::Custom(steps) => steps.formula(),
+        }
+    }
+
+    // mode family parent + mode number (ex: `Dorian` -> `Maj`, `2`, `Altered` -> `MelMin`, `7`)
+    // (Note: `None` for scales outside of the major, melodic and harmonic minor families)
+    pub fn parent(&self) -> Option<(ScaleQuality, usize)> {
+        MODE_FAMILIES.iter().find_map(|family| {
+            family
+                .iter()
+                .position(|quality| quality == self)
+                .map(|index| (family[0], index + 1))
+        })
+    }
+
+    // the family mode starting from this scale's `number` degree (ex: `Maj` mode `2` -> `Dorian`,
+    // `Dorian` mode `2` -> `Phrygian`) - custom scales rotate their own steps
+    pub fn mode(&self, number: usize) -> Option<ScaleQuality> {
+        if let ScaleQuality::Custom(steps) = self {
+            return steps.mode(number).map(ScaleQuality::Custom);
+        }
+        if !(1..=7).contains(&number) {
+            return None;
+        }
+
+        MODE_FAMILIES.iter().find_map(|family| {
+            let index = family.iter().position(|quality| quality == self)?;
+            Some(family[(index + number - 1) % 7])
+        })
+    }
+
+    // same notes from more than one root (ex: whole tone, half-whole, augmented)
+    pub fn is_symmetric(&self) -> bool {
+        let semitones = self
+            .formula()
+            .iter()
+            .map(|(_, semitones)| *semitones)
+            .collect::<HashSet<_>>();
+
+        (1..12).any(|shift| {
+            semitones
+                .iter()
+                .all(|semitone| semitones.contains(&((semitone + shift) % 12)))
+        })
+    }
+
+    // symmetric scales and scales with a repeated letter (ex: bebop, blues) can't keep one letter
+    // per note, so they're spelled plainly instead (see `ScaleName::spell`)
+    fn spells_plainly(&self) -> bool {
+        let formula = self.formula();
+        let letters = formula
+            .iter()
+            .map(|(degree, _)| *degree)
+            .collect::<HashSet<_>>();
+
+        letters.len() < formula.len() || self.is_symmetric()
+    }
 }
 
 impl Display for ScaleQuality {
@@ -20,14 +356,337 @@ impl Display for ScaleQuality {
             ScaleQuality::MinPent => write!(f, "minor pentatonic"),
             ScaleQuality::HarmMin => write!(f, "harmonic minor"),
             ScaleQuality::MelMin => write!(f, "melodic minor"),
+            ScaleQuality::Dorian => write!(f, "dorian"),
+            ScaleQuality::Phrygian => write!(f, "phrygian"),
+            ScaleQuality::Lydian => write!(f, "lydian"),
+            ScaleQuality::Mixolydian => write!(f, "mixolydian"),
+            ScaleQuality::Locrian => write!(f, "locrian"),
+            ScaleQuality::DorianFlatTwo => write!(f, "dorian b2"),
+            ScaleQuality::LydianAug => write!(f, "lydian augmented"),
+            ScaleQuality::LydianDom => write!(f, "lydian dominant"),
+            ScaleQuality::MixolydianFlatSix => write!(f, "mixolydian b6"),
+            ScaleQuality::LocrianSharpTwo => write!(f, "locrian #2"),
+            ScaleQuality::Altered => write!(f, "altered"),
+            ScaleQuality::LocrianSharpSix => write!(f, "locrian #6"),
+            ScaleQuality::IonianSharpFive => write!(f, "ionian #5"),
+            ScaleQuality::DorianSharpFour => write!(f, "dorian #4"),
+            ScaleQuality::PhrygianDom => write!(f, "phrygian dominant"),
+            ScaleQuality::LydianSharpTwo => write!(f, "lydian #2"),
+            ScaleQuality::Ultralocrian => write!(f, "ultralocrian"),
+            ScaleQuality::WholeTone => write!(f, "whole tone"),
+            ScaleQuality::HalfWholeDim => write!(f, "half-whole diminished"),
+            ScaleQuality::WholeHalfDim => write!(f, "whole-half diminished"),
+            ScaleQuality::Augmented => write!(f, "augmented"),
+            ScaleQuality::Blues => write!(f, "blues"),
+            ScaleQuality::MajBlues => write!(f, "major blues"),
+            ScaleQuality::BebopDom => write!(f, "bebop dominant"),
+            ScaleQuality::BebopMaj => write!(f, "bebop major"),
+            ScaleQuality::BebopMin => write!(f, "bebop minor"),
+            ScaleQuality::HungarianMin => write!(f, "hungarian minor"),
+            ScaleQuality::HungarianMaj => write!(f, "hungarian major"),
+            ScaleQuality::DoubleHarm => write!(f, "double harmonic"),
+            ScaleQuality::NeapolitanMaj => write!(f, "neapolitan major"),
+            ScaleQuality::NeapolitanMin => write!(f, "neapolitan minor"),
+            ScaleQuality::Persian => write!(f, "persian"),
+            ScaleQuality::Enigmatic => write!(f, "enigmatic"),
+            ScaleQuality::Prometheus => write!(f, "prometheus"),
+            ScaleQuality::Hirajoshi => write!(f, "hirajoshi"),
+            ScaleQuality::InSen => write!(f, "in sen"),
+            ScaleQuality::Iwato => write!(f, "iwato"),
+            ScaleQuality::Kumoi => write!(f, "kumoi"),
+            ScaleQuality::Yo => write!(f, "yo"),
+            ScaleQuality::Egyptian => write!(f, "egyptian"),
+            ScaleQuality::Custom(steps) => write!(f, "custom {}", steps),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("ERROR: failed to read `{input}` as a custom scale: {reason} (Note: steps look like `WWHWWWH` or `2212221`, semitones like `[0, 2, 4, 5, 7, 9, 11]`, Scala intervals like `9/8 5/4 4/3 3/2 5/3 15/8 2/1` or `200.0 400.0 500.0 ...` - up to one octave)")]
+pub struct ScaleStepsError {
+    input: String,
+    reason: String,
+}
+
+impl ScaleStepsError {
+    fn new(input: &str, reason: String) -> Self {
+        Self {
+            input: input.to_string(),
+            reason,
+        }
+    }
+
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+// widest gap (cents) between a Scala interval and the semitone it rounds to
+// (Note: wider intervals, ex: the `7/4` harmonic seventh, need an `Edo` rather than 12-TET)
+const SCALA_TOLERANCE_CENTS: f64 = 25.0;
+
+// set of semitones above the root of a custom scale (bit `n` is `n` semitones, the root always
+// sits on bit `0`) (ex: `WWHWWWH` -> `0, 2, 4, 5, 7, 9, 11`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScaleSteps(u16);
+
+impl ScaleSteps {
+    // `W` (2), `H` (1), `A` (3, an augmented second) or digits, ex: `WWHWWWH`, `2212221`, `3-2-1-1-3-2`
+    // (Note: steps may close the octave or stop short of it - the last step back to the root
+    // is implied, ex: `WWHWWW`)
+    pub fn from_steps(steps: &str) -> Result<Self, ScaleStepsError> {
+        let is_separator = |c: char| c.is_whitespace() || matches!(c, '-' | ',');
+        // separated steps may take two digits (ex: `10-2`), packed ones are one character each
+        let separated = steps.trim().contains(is_separator);
+        let mut semitones = vec![0];
+
+        for token in steps.split(is_separator).filter(|token| !token.is_empty()) {
+            let token_steps = match token.parse::<usize>() {
+                Ok(step) if separated => vec![step],
+                _ => token
+                    .chars()
+                    .map(|c| match c {
+                        'W' | 'w' => Ok(2),
+                        'H' | 'h' => Ok(1),
+                        'A' | 'a' => Ok(3),
+                        '1'..='9' => Ok(c as usize - '0' as usize),
+                        _ => Err(ScaleStepsError::new(steps, format!("unknown step `{}`", c))),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            };
+            for step in token_steps {
+                let last = semitones[semitones.len() - 1];
+                semitones.push(last + step);
+            }
+        }
+
+        match semitones.pop() {
+            None | Some(0) => Err(ScaleStepsError::new(steps, "no steps".to_string())),
+            Some(12) => Self::from_semitones(&semitones),
+            Some(total) if total < 12 => {
+                semitones.push(total);
+                Self::from_semitones(&semitones)
+            }
+            Some(total) => Err(ScaleStepsError::new(
+                steps,
+                format!("steps span `{}` semitones, past the octave", total),
+            )),
+        }
+    }
+
+    // rising semitones above the root, with or without the root's `0` (ex: `[0, 2, 3, 5, 7, 8, 11]`)
+    pub fn from_semitones(semitones: &[usize]) -> Result<Self, ScaleStepsError> {
+        let input = format!("{:?}", semitones);
+        let mut bits = 1u16;
+        let mut last = 0;
+
+        for (index, semitone) in semitones.iter().enumerate() {
+            if index == 0 && *semitone == 0 {
+                continue;
+            }
+            if *semitone <= last || *semitone >= 12 {
+                return Err(ScaleStepsError::new(
+                    &input,
+                    format!(
+                        "semitones should rise from `1` to `11`, found `{}` after `{}`",
+                        semitone, last
+                    ),
+                ));
+            }
+            bits |= 1 << semitone;
+            last = *semitone;
+        }
+
+        // the root alone isn't a scale (ex: `[]`, `[0]`, a Scala file holding only `2/1`)
+        if bits.count_ones() < 2 {
+            return Err(ScaleStepsError::new(
+                &input,
+                "a scale needs at least one note above the root".to_string(),
+            ));
+        }
+
+        Ok(Self(bits))
+    }
+
+    // intervals of a Scala `.scl` file - description line, note count, then one cents (`200.0`)
+    // or ratio (`9/8`, `2`) pitch per line - or a bare list of them (ex: `9/8 5/4 3/2 2/1`)
+    // (Note: pitches round to the nearest semitone, a closing `2/1` / `1200.0` octave is dropped)
+    pub fn from_scala(text: &str) -> Result<Self, ScaleStepsError> {
+        let input = text.trim();
+        let lines = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.starts_with('!'))
+            .collect::<Vec<_>>();
+
+        // the first non-comment line is the description - even when it's empty - and the note
+        // count comes right after it (ex: `!x.scl`, `!`, ``, `7`, `9/8`, ...)
+        let count = lines.get(1).and_then(|line| line.parse::<usize>().ok());
+
+        let pitches = match count {
+            Some(count) => {
+                let pitches = lines[2..]
+                    .iter()
+                    .filter_map(|line| line.split_whitespace().next())
+                    .take(count)
+                    .collect::<Vec<_>>();
+                if pitches.len() != count {
+                    return Err(ScaleStepsError::new(
+                        input,
+                        format!("expected `{}` pitches, found `{}`", count, pitches.len()),
+                    ));
+                }
+                pitches
+            }
+            None => lines
+                .iter()
+                .flat_map(|line| line.split(|c: char| c.is_whitespace() || c == ','))
+                .filter(|token| !token.is_empty())
+                .collect(),
+        };
+
+        let mut semitones = Vec::with_capacity(pitches.len());
+        for (index, pitch) in pitches.iter().enumerate() {
+            let cents = scala_cents(pitch).ok_or_else(|| {
+                ScaleStepsError::new(input, format!("`{}` is not a pitch", pitch))
+            })?;
+            let semitone = (cents / 100.0).round();
+
+            if (cents - semitone * 100.0).abs() > SCALA_TOLERANCE_CENTS {
+                return Err(ScaleStepsError::new(
+                    input,
+                    format!(
+                        "`{}` sits `{:+.1}` cents off the nearest semitone",
+                        pitch,
+                        cents - semitone * 100.0
+                    ),
+                ));
+            }
+            // the octave closes the scale
+            if semitone == 12.0 && index == pitches.len() - 1 {
+                break;
+            }
+            if !(1.0..12.0).contains(&semitone) {
+                return Err(ScaleStepsError::new(
+                    input,
+                    format!("`{}` falls outside of the octave", pitch),
+                ));
+            }
+            semitones.push(semitone as usize);
+        }
+
+        Self::from_semitones(&semitones).map_err(|err| ScaleStepsError::new(input, err.reason))
+    }
+
+    // semitones above the root, root first (ex: `0, 2, 4, 5, 7, 9, 11`)
+    pub fn semitones(&self) -> Vec<usize> {
+        (0..12)
+            .filter(|semitone| self.0 & 1 << semitone != 0)
+            .collect()
+    }
+
+    // semitones between neighbouring notes, back up to the root's octave (ex: `2, 2, 1, 2, 2, 2, 1`)
+    pub fn steps(&self) -> Vec<usize> {
+        let semitones = self.semitones();
+
+        semitones
+            .iter()
+            .zip(semitones.iter().skip(1).chain([&12]))
+            .map(|(low, high)| high - low)
+            .collect()
+    }
+
+    // same steps started from the `number` note (ex: `WWHWWWH` mode `2` -> `WHWWWHW`)
+    fn mode(&self, number: usize) -> Option<ScaleSteps> {
+        let semitones = self.semitones();
+        let offset = *semitones.get(number.checked_sub(1)?)?;
+        let bits = semitones.iter().fold(0u16, |bits, semitone| {
+            bits | 1 << ((semitone + 12 - offset) % 12)
+        });
+
+        Some(ScaleSteps(bits))
+    }
+
+    // seven-note scales take one letter per note when every note has a `ScaleDegree` that way
+    // (ex: `0, 1, 3, 4, 6, 8, 10` -> `b2`, `b3`, `b4`, ...), other scales take each semitone's
+    // common degree (ex: `6` is a `b5` beside a `4`, a `#4` without one)
+    fn formula(&self) -> Vec<(usize, usize)> {
+        let semitones = self.semitones();
+
+        let lettered = semitones
+            .iter()
+            .enumerate()
+            .map(|(index, semitones)| (index + 1, *semitones))
+            .collect::<Vec<_>>();
+        let one_letter_each = semitones.len() == 7
+            && lettered.iter().all(|(degree, semitones)| {
+                ScaleDegree::from_formula(*degree, *semitones, &NoteName::C).is_some()
+            });
+        if one_letter_each {
+            return lettered;
         }
+
+        let has = |semitone: usize| semitones.contains(&semitone);
+        semitones
+            .iter()
+            .map(|semitone| {
+                let degree = match semitone {
+                    0 => 1,
+                    1 | 2 => 2,
+                    3 if has(4) && !has(2) => 2,
+                    3 | 4 => 3,
+                    5 => 4,
+                    6 if !has(5) => 4,
+                    6 | 7 => 5,
+                    8 if !has(7) => 5,
+                    8 | 9 => 6,
+                    _ => 7,
+                };
+                (degree, *semitone)
+            })
+            .collect()
+    }
+}
+
+// steps as digits (ex: `2212221`) - dashed once a step needs two digits (ex: `10-2`)
+impl Display for ScaleSteps {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let steps = self.steps();
+        let separator = match steps.iter().any(|step| *step > 9) {
+            true => "-",
+            false => "",
+        };
+        let steps = steps
+            .iter()
+            .map(|step| step.to_string())
+            .collect::<Vec<_>>()
+            .join(separator);
+
+        write!(f, "{}", steps)
+    }
+}
+
+// cents above the root of one Scala pitch - `.` marks cents, anything else is a ratio
+fn scala_cents(pitch: &str) -> Option<f64> {
+    if pitch.contains('.') {
+        return pitch.parse::<f64>().ok();
     }
+
+    let (num, den) = pitch.split_once('/').unwrap_or((pitch, "1"));
+    let ratio = num.parse::<u64>().ok()? as f64 / den.parse::<u64>().ok()? as f64;
+
+    (ratio.is_finite() && ratio > 0.0).then(|| 1200.0 * ratio.log2())
 }
 
 #[derive(Debug, thiserror::Error)]
-#[error("{0}")]
+#[error("Error trying in conversion of `&str` scale name `{0}` to `ScaleName` enum variant (Note: expects a root note + space + quality ex: `C Maj`, `C#/Db Har`, `Eb Mel`, `A minor pentatonic`, `D Dorian`, `G lydian dominant`, `C custom WWHWWWH`)")]
 pub struct ScaleNameError(String);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ScaleName {
     C(ScaleQuality),
     CSharpDFlat(ScaleQuality),
@@ -43,6 +702,202 @@ pub enum ScaleName {
     B(ScaleQuality),
 }
 
+impl ScaleName {
+    pub fn new(root: &NoteName, quality: ScaleQuality) -> Self {
+        match root {
+            NoteName::C => ScaleName::C(quality),
+            NoteName::CSharpDFlat => ScaleName::CSharpDFlat(quality),
+            NoteName::D => ScaleName::D(quality),
+            NoteName::DSharpEFlat => ScaleName::DSharpEFlat(quality),
+            NoteName::E => ScaleName::E(quality),
+            NoteName::F => ScaleName::F(quality),
+            NoteName::FSharpGFlat => ScaleName::FSharpGFlat(quality),
+            NoteName::G => ScaleName::G(quality),
+            NoteName::GSharpAFlat => ScaleName::GSharpAFlat(quality),
+            NoteName::A => ScaleName::A(quality),
+            NoteName::ASharpBFlat => ScaleName::ASharpBFlat(quality),
+            NoteName::B => ScaleName::B(quality),
+        }
+    }
+
+    pub fn get_root(&self) -> NoteName {
+        match self {
+            ScaleName::C(_) => NoteName::C,
+            ScaleName::CSharpDFlat(_) => NoteName::CSharpDFlat,
+            ScaleName::D(_) => NoteName::D,
+            ScaleName::DSharpEFlat(_) => NoteName::DSharpEFlat,
+            ScaleName::E(_) => NoteName::E,
+            ScaleName::F(_) => NoteName::F,
+            ScaleName::FSharpGFlat(_) => NoteName::FSharpGFlat,
+            ScaleName::G(_) => NoteName::G,
+            ScaleName::GSharpAFlat(_) => NoteName::GSharpAFlat,
+            ScaleName::A(_) => NoteName::A,
+            ScaleName::ASharpBFlat(_) => NoteName::ASharpBFlat,
+            ScaleName::B(_) => NoteName::B,
+        }
+    }
+
+    pub fn get_quality(&self) -> ScaleQuality {
+        match self {
+            ScaleName::C(qual) => *qual,
+            ScaleName::CSharpDFlat(qual) => *qual,
+            ScaleName::D(qual) => *qual,
+            ScaleName::DSharpEFlat(qual) => *qual,
+            ScaleName::E(qual) => *qual,
+            ScaleName::F(qual) => *qual,
+            ScaleName::FSharpGFlat(qual) => *qual,
+            ScaleName::G(qual) => *qual,
+            ScaleName::GSharpAFlat(qual) => *qual,
+            ScaleName::A(qual) => *qual,
+            ScaleName::ASharpBFlat(qual) => *qual,
+            ScaleName::B(qual) => *qual,
+        }
+    }
+
+    // letter-based scale notes - each letter appears once in seven-note scales, symmetric and
+    // repeated-letter scales take plain spellings (ex: `D` half-whole -> `D Eb F F# G# A B C`)
+    // (Note: the `key` decides the tonic's spelling when it holds the tonic, ex: `D#` in `B` major)
+    pub fn spell(&self, key: Option<&Key>) -> Vec<SpelledNote> {
+        match key.and_then(|key| key.get_diatonic_spelling(&self.get_root())) {
+            Some(tonic) => self.spell_from_tonic(tonic),
+            None => self.spell_from_tonic(fewest_accidentals_by(&self.get_root(), |tonic| {
+                self.spell_from_tonic(tonic)
+            })),
+        }
+    }
+
+    // scale notes from a tonic letter already decided on (ex: `Bb` altered rather than `A#`)
+    // (Note: `tonic` should name the scale's own root - any other note falls back to `spell`)
+    pub fn spelled_from(&self, tonic: &SpelledNote) -> Vec<SpelledNote> {
+        if tonic.note_name() != self.get_root() {
+            return self.spell(None);
+        }
+
+        self.spell_from_tonic(*tonic)
+    }
+
+    fn spell_from_tonic(&self, tonic: SpelledNote) -> Vec<SpelledNote> {
+        let quality = self.get_quality();
+        match quality.spells_plainly() {
+            true => spell_degrees_plainly(tonic, &quality.formula()),
+            false => spell_degrees(tonic, &quality.formula()),
+        }
+    }
+
+    // mode family parent + mode number (ex: `D Dorian` -> `C major`, `2`)
+    pub fn parent(&self) -> Option<(ScaleName, usize)> {
+        let (quality, number) = self.get_quality().parent()?;
+        let (_, semitones) = quality.formula()[number - 1];
+        let root = NoteName::from_pitch_class(self.get_root().pitch_class() + 12 - semitones);
+
+        Some((ScaleName::new(&root, quality), number))
+    }
+
+    // mode starting from this scale's `number` degree, same notes (ex: `C major` mode `2` -> `D Dorian`)
+    pub fn mode(&self, number: usize) -> Option<ScaleName> {
+        let quality = self.get_quality().mode(number)?;
+        let (_, semitones) = self.get_quality().formula()[number - 1];
+        let root = NoteName::from_pitch_class(self.get_root().pitch_class() + semitones);
+
+        Some(ScaleName::new(&root, quality))
+    }
+
+    // every mode of this scale, from its own first degree (ex: `C major`, `D dorian`, `E phrygian`, ...)
+    pub fn modes(&self) -> Vec<ScaleName> {
+        (1..=self.get_quality().formula().len())
+            .filter_map(|number| self.mode(number))
+            .collect()
+    }
+}
+
+impl TryFrom<&str> for ScaleName {
+    type Error = ScaleNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (root, quality) = value
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| ScaleNameError(value.to_string()))?;
+        let root = NoteName::try_from(root).map_err(|_| ScaleNameError(value.to_string()))?;
+
+        // short names from `major_minor_scales.txt` + this crate's `Display` names + common aliases
+        let quality = quality
+            .trim()
+            .to_lowercase()
+            .replace('♯', "#")
+            .replace('♭', "b");
+        let quality = match quality.as_str() {
+            "maj" | "major" | "ionian" => ScaleQuality::Maj,
+            "min" | "minor" | "aeolian" => ScaleQuality::Min,
+            "har" | "harm" | "harmonic minor" => ScaleQuality::HarmMin,
+            "mel" | "melodic minor" => ScaleQuality::MelMin,
+            "maj pent" | "major pentatonic" => ScaleQuality::MajPent,
+            "min pent" | "minor pentatonic" => ScaleQuality::MinPent,
+            "dor" | "dorian" => ScaleQuality::Dorian,
+            "phr" | "phrygian" => ScaleQuality::Phrygian,
+            "lyd" | "lydian" => ScaleQuality::Lydian,
+            "mix" | "mixolydian" => ScaleQuality::Mixolydian,
+            "loc" | "locrian" => ScaleQuality::Locrian,
+            "dorian b2" | "phrygian #6" => ScaleQuality::DorianFlatTwo,
+            "lydian augmented" | "lydian #5" => ScaleQuality::LydianAug,
+            "lydian dominant" | "lydian b7" | "overtone" => ScaleQuality::LydianDom,
+            "mixolydian b6" | "aeolian dominant" => ScaleQuality::MixolydianFlatSix,
+            "locrian #2" | "half diminished" => ScaleQuality::LocrianSharpTwo,
+            "altered" | "super locrian" => ScaleQuality::Altered,
+            "locrian #6" => ScaleQuality::LocrianSharpSix,
+            "ionian #5" | "ionian augmented" => ScaleQuality::IonianSharpFive,
+            "dorian #4" | "ukrainian dorian" => ScaleQuality::DorianSharpFour,
+            "phrygian dominant" | "spanish phrygian" => ScaleQuality::PhrygianDom,
+            "lydian #2" => ScaleQuality::LydianSharpTwo,
+            "ultralocrian" | "altered bb7" => ScaleQuality::Ultralocrian,
+            "whole tone" | "whole-tone" => ScaleQuality::WholeTone,
+            "half-whole diminished" | "half whole" | "half-whole" | "dominant diminished" => {
+                ScaleQuality::HalfWholeDim
+            }
+            "whole-half diminished" | "whole half" | "whole-half" | "diminished" => {
+                ScaleQuality::WholeHalfDim
+            }
+            "augmented" => ScaleQuality::Augmented,
+            "blues" | "minor blues" => ScaleQuality::Blues,
+            "major blues" => ScaleQuality::MajBlues,
+            "bebop dominant" | "bebop" => ScaleQuality::BebopDom,
+            "bebop major" => ScaleQuality::BebopMaj,
+            "bebop minor" | "bebop dorian" => ScaleQuality::BebopMin,
+            "hungarian minor" | "gypsy minor" => ScaleQuality::HungarianMin,
+            "hungarian major" => ScaleQuality::HungarianMaj,
+            "double harmonic" | "byzantine" | "arabic" => ScaleQuality::DoubleHarm,
+            "neapolitan major" => ScaleQuality::NeapolitanMaj,
+            "neapolitan minor" => ScaleQuality::NeapolitanMin,
+            "persian" => ScaleQuality::Persian,
+            "enigmatic" => ScaleQuality::Enigmatic,
+            "prometheus" => ScaleQuality::Prometheus,
+            "hirajoshi" => ScaleQuality::Hirajoshi,
+            "in sen" | "insen" | "in-sen" => ScaleQuality::InSen,
+            "iwato" => ScaleQuality::Iwato,
+            "kumoi" => ScaleQuality::Kumoi,
+            "yo" => ScaleQuality::Yo,
+            "egyptian" | "suspended pentatonic" => ScaleQuality::Egyptian,
+            // custom step patterns (ex: `C custom WWHWWWH`, `C custom 2212221`)
+            quality => quality
+                .strip_prefix("custom ")
+                .and_then(|steps| ScaleSteps::from_steps(steps).ok())
+                .map(ScaleQuality::Custom)
+                .ok_or_else(|| ScaleNameError(value.to_string()))?,
+        };
+
+        Ok(ScaleName::new(&root, quality))
+    }
+}
+
+impl Transpose for ScaleName {
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError> {
+        Ok(ScaleName::new(
+            &self.get_root().transpose(semitones)?,
+            self.get_quality(),
+        ))
+    }
+}
+
 impl Display for ScaleName {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -62,6 +917,7 @@ impl Display for ScaleName {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ScaleDegree {
     First(NoteName),
     FlatSecond(NoteName),
@@ -69,6 +925,7 @@ pub enum ScaleDegree {
     SharpSecond(NoteName),
     FlatThird(NoteName),
     Third(NoteName),
+    FlatFourth(NoteName),
     Fourth(NoteName),
     SharpFourth(NoteName),
     FlatFifth(NoteName),
@@ -76,37 +933,498 @@ pub enum ScaleDegree {
     SharpFifth(NoteName),
     FlatSixth(NoteName),
     Sixth(NoteName),
+    SharpSixth(NoteName),
+    DoubleFlatSeventh(NoteName),
     FlatSeventh(NoteName),
     Seventh(NoteName),
 }
 
-impl Display for ScaleDegree {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl ScaleDegree {
+    // degree for one `(degree number, semitones above the root)` formula step
+    // (ex: `(3, 3)` -> `FlatThird`, `(4, 6)` -> `SharpFourth`, `(5, 6)` -> `FlatFifth`)
+    // (Note: `None` for steps without a variant, ex: a doubly altered second)
+    pub fn from_formula(degree: usize, semitones: usize, note: &NoteName) -> Option<Self> {
+        let note = *note;
+
+        match (degree, semitones % 12) {
+            (1, 0) => Some(ScaleDegree::First(note)),
+            (2, 1) => Some(ScaleDegree::FlatSecond(note)),
+            (2, 2) => Some(ScaleDegree::Second(note)),
+            (2, 3) => Some(ScaleDegree::SharpSecond(note)),
+            (3, 3) => Some(ScaleDegree::FlatThird(note)),
+            (3, 4) => Some(ScaleDegree::Third(note)),
+            (4, 4) => Some(ScaleDegree::FlatFourth(note)),
+            (4, 5) => Some(ScaleDegree::Fourth(note)),
+            (4, 6) => Some(ScaleDegree::SharpFourth(note)),
+            (5, 6) => Some(ScaleDegree::FlatFifth(note)),
+            (5, 7) => Some(ScaleDegree::Fifth(note)),
+            (5, 8) => Some(ScaleDegree::SharpFifth(note)),
+            (6, 8) => Some(ScaleDegree::FlatSixth(note)),
+            (6, 9) => Some(ScaleDegree::Sixth(note)),
+            (6, 10) => Some(ScaleDegree::SharpSixth(note)),
+            (7, 9) => Some(ScaleDegree::DoubleFlatSeventh(note)),
+            (7, 10) => Some(ScaleDegree::FlatSeventh(note)),
+            (7, 11) => Some(ScaleDegree::Seventh(note)),
+            _ => None,
+        }
+    }
+
+    pub fn note(&self) -> &NoteName {
+        match self {
+            ScaleDegree::First(note)
+            | ScaleDegree::FlatSecond(note)
+            | ScaleDegree::Second(note)
+            | ScaleDegree::SharpSecond(note)
+            | ScaleDegree::FlatThird(note)
+            | ScaleDegree::Third(note)
+            | ScaleDegree::FlatFourth(note)
+            | ScaleDegree::Fourth(note)
+            | ScaleDegree::SharpFourth(note)
+            | ScaleDegree::FlatFifth(note)
+            | ScaleDegree::Fifth(note)
+            | ScaleDegree::SharpFifth(note)
+            | ScaleDegree::FlatSixth(note)
+            | ScaleDegree::Sixth(note)
+            | ScaleDegree::SharpSixth(note)
+            | ScaleDegree::DoubleFlatSeventh(note)
+            | ScaleDegree::FlatSeventh(note)
+            | ScaleDegree::Seventh(note) => note,
+        }
+    }
+
+    // `(degree number, semitones above the root)` - the inverse of `from_formula`
+    pub fn formula(&self) -> (usize, usize) {
+        match self {
+            ScaleDegree::First(_) => (1, 0),
+            ScaleDegree::FlatSecond(_) => (2, 1),
+            ScaleDegree::Second(_) => (2, 2),
+            ScaleDegree::SharpSecond(_) => (2, 3),
+            ScaleDegree::FlatThird(_) => (3, 3),
+            ScaleDegree::Third(_) => (3, 4),
+            ScaleDegree::FlatFourth(_) => (4, 4),
+            ScaleDegree::Fourth(_) => (4, 5),
+            ScaleDegree::SharpFourth(_) => (4, 6),
+            ScaleDegree::FlatFifth(_) => (5, 6),
+            ScaleDegree::Fifth(_) => (5, 7),
+            ScaleDegree::SharpFifth(_) => (5, 8),
+            ScaleDegree::FlatSixth(_) => (6, 8),
+            ScaleDegree::Sixth(_) => (6, 9),
+            ScaleDegree::SharpSixth(_) => (6, 10),
+            ScaleDegree::DoubleFlatSeventh(_) => (7, 9),
+            ScaleDegree::FlatSeventh(_) => (7, 10),
+            ScaleDegree::Seventh(_) => (7, 11),
+        }
+    }
+
+    pub fn number(&self) -> usize {
+        self.formula().0
+    }
+
+    pub fn semitones(&self) -> usize {
+        self.formula().1
+    }
+
+    // degree symbol without its note (ex: `b̂3` for `FlatThird`)
+    fn symbol(&self) -> String {
         let combining_circumflex = '\u{0302}';
 
         match self {
-            ScaleDegree::First(note) => write!(f, "({}1) {note}", combining_circumflex),
-            ScaleDegree::FlatSecond(note) => write!(f, "(b{}2) {note}", combining_circumflex),
-            ScaleDegree::Second(note) => write!(f, "({}2/9) {note}", combining_circumflex),
-            ScaleDegree::SharpSecond(note) => write!(f, "(#{}2/9) {note}", combining_circumflex),
-            ScaleDegree::FlatThird(note) => write!(f, "(b{}3) {note}", combining_circumflex),
-            ScaleDegree::Third(note) => write!(f, "({}3) {note}", combining_circumflex),
-            ScaleDegree::Fourth(note) => write!(f, "({}4) {note}", combining_circumflex),
-            ScaleDegree::SharpFourth(note) => write!(f, "(#{}4) {note}", combining_circumflex),
-            ScaleDegree::FlatFifth(note) => write!(f, "(b{}5) {note}", combining_circumflex),
-            ScaleDegree::Fifth(note) => write!(f, "({}5) {note}", combining_circumflex),
-            ScaleDegree::SharpFifth(note) => write!(f, "(#{}5) {note}", combining_circumflex),
-            ScaleDegree::FlatSixth(note) => write!(f, "(b{}6) {note}", combining_circumflex),
-            ScaleDegree::Sixth(note) => write!(f, "({}6) {note}", combining_circumflex),
-            ScaleDegree::FlatSeventh(note) => write!(f, "(b{}7) {note}", combining_circumflex),
-            ScaleDegree::Seventh(note) => write!(f, "({}7) {note}", combining_circumflex),
+            ScaleDegree::First(_) => format!("{}1", combining_circumflex),
+            ScaleDegree::FlatSecond(_) => format!("b{}2", combining_circumflex),
+            ScaleDegree::Second(_) => format!("{}2/9", combining_circumflex),
+            ScaleDegree::SharpSecond(_) => format!("#{}2/9", combining_circumflex),
+            ScaleDegree::FlatThird(_) => format!("b{}3", combining_circumflex),
+            ScaleDegree::Third(_) => format!("{}3", combining_circumflex),
+            ScaleDegree::FlatFourth(_) => format!("b{}4", combining_circumflex),
+            ScaleDegree::Fourth(_) => format!("{}4", combining_circumflex),
+            ScaleDegree::SharpFourth(_) => format!("#{}4", combining_circumflex),
+            ScaleDegree::FlatFifth(_) => format!("b{}5", combining_circumflex),
+            ScaleDegree::Fifth(_) => format!("{}5", combining_circumflex),
+            ScaleDegree::SharpFifth(_) => format!("#{}5", combining_circumflex),
+            ScaleDegree::FlatSixth(_) => format!("b{}6", combining_circumflex),
+            ScaleDegree::Sixth(_) => format!("{}6", combining_circumflex),
+            ScaleDegree::SharpSixth(_) => format!("#{}6", combining_circumflex),
+            ScaleDegree::DoubleFlatSeventh(_) => format!("bb{}7", combining_circumflex),
+            ScaleDegree::FlatSeventh(_) => format!("b{}7", combining_circumflex),
+            ScaleDegree::Seventh(_) => format!("{}7", combining_circumflex),
         }
     }
 }
 
+impl Display for ScaleDegree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}) {}", self.symbol(), self.note())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Error trying to apply spelled notes `{0}` to `ScaleSpelling` (Note: expects one spelled note per scale degree, matching each degree's `NoteName`)")]
+pub struct ScaleSpellingError(String);
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct ScaleSpelling {
     name: ScaleName,
     spelling: Vec<ScaleDegree>,
+    spelled_notes: Vec<SpelledNote>,
     quality: ScaleQuality,
     root: NoteName,
 }
+
+impl ScaleSpelling {
+    // degrees from the quality's formula, spelled by letter (see `ScaleName::spell`)
+    pub fn new(name: &ScaleName) -> Self {
+        let root = name.get_root();
+        let quality = name.get_quality();
+        let spelling = quality
+            .formula()
+            .into_iter()
+            .map(|(degree, semitones)| {
+                let note = NoteName::from_pitch_class(root.pitch_class() + semitones);
+                ScaleDegree::from_formula(degree, semitones, &note)
+                    .expect("should have a `ScaleDegree` for every step of a scale formula")
+            })
+            .collect();
+
+        Self {
+            name: *name,
+            spelling,
+            spelled_notes: name.spell(None),
+            quality,
+            root,
+        }
+    }
+
+    // custom scale from a step pattern (ex: `C`, `WHWWHWW`) - see `ScaleSteps::from_steps`
+    pub fn from_steps(root: &NoteName, steps: &str) -> Result<Self, ScaleStepsError> {
+        Ok(Self::custom(root, ScaleSteps::from_steps(steps)?))
+    }
+
+    // custom scale from semitones above the root (ex: `C`, `[0, 1, 4, 5, 7, 8, 10]`)
+    pub fn from_semitones(root: &NoteName, semitones: &[usize]) -> Result<Self, ScaleStepsError> {
+        Ok(Self::custom(root, ScaleSteps::from_semitones(semitones)?))
+    }
+
+    // custom scale from a Scala file or interval list - see `ScaleSteps::from_scala`
+    pub fn from_scala(root: &NoteName, text: &str) -> Result<Self, ScaleStepsError> {
+        Ok(Self::custom(root, ScaleSteps::from_scala(text)?))
+    }
+
+    fn custom(root: &NoteName, steps: ScaleSteps) -> Self {
+        Self::new(&ScaleName::new(root, ScaleQuality::Custom(steps)))
+    }
+
+    // swap in letter-specific spellings (ex: `Gb` major over the default `F#` major)
+    pub fn with_spelled_notes(
+        mut self,
+        spelled_notes: &[SpelledNote],
+    ) -> Result<Self, ScaleSpellingError> {
+        let matches_degrees = spelled_notes.len() == self.spelling.len()
+            && spelled_notes
+                .iter()
+                .zip(self.spelling.iter())
+                .all(|(spelled, degree)| spelled.note_name() == *degree.note());
+
+        if !matches_degrees {
+            let notes = spelled_notes
+                .iter()
+                .map(|spelled| spelled.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(ScaleSpellingError(notes));
+        }
+
+        self.spelled_notes = spelled_notes.to_vec();
+        Ok(self)
+    }
+
+    pub fn name(&self) -> &ScaleName {
+        &self.name
+    }
+
+    pub fn quality(&self) -> ScaleQuality {
+        self.quality
+    }
+
+    pub fn root(&self) -> NoteName {
+        self.root
+    }
+
+    pub fn spelling(&self) -> &Vec<ScaleDegree> {
+        &self.spelling
+    }
+
+    pub fn spelled_notes(&self) -> &Vec<SpelledNote> {
+        &self.spelled_notes
+    }
+
+    // degree by number (ex: `3` -> `FlatThird(D#/Eb)` in `C` minor)
+    // (Note: `None` for the degrees a pentatonic scale skips - two degrees sharing a number
+    // return the lower one, ex: the `b2` of a half-whole diminished scale)
+    pub fn get_degree(&self, number: usize) -> Option<&ScaleDegree> {
+        self.spelling
+            .iter()
+            .find(|degree| degree.number() == number)
+    }
+
+    // degree `note` sits on (ex: `G` -> `Fifth(G)` in `C` major) - `None` outside of the scale
+    pub fn get_note_degree(&self, note: &NoteName) -> Option<&ScaleDegree> {
+        self.spelling.iter().find(|degree| degree.note() == note)
+    }
+
+    pub fn get_spelled_note(&self, note: &NoteName) -> Option<&SpelledNote> {
+        self.spelled_notes
+            .iter()
+            .find(|spelled| spelled.note_name() == *note)
+    }
+
+    // chord stacked in thirds on each scale note, in scale order (see `Key::diatonic_chord`)
+    // (ex: `C` major -> `Cmaj`, `Dmin`, `Emin`, ... - `None` for stacks without a triad / seventh
+    // quality and for every note of a scale with other than seven notes)
+    pub fn harmonize(&self, seventh: bool) -> Vec<Option<ChordName>> {
+        let key = Key::new(self.spelled_notes[0], self.quality);
+
+        (1..=self.spelling.len())
+            .map(|degree| key.diatonic_chord(degree, seventh))
+            .collect()
+    }
+
+    // `transposed` with this spelling's letters moved along, keeping its own spelling when
+    // a moved note would need more than a double accidental
+    fn carry_spellings(
+        &self,
+        transposed: Self,
+        move_note: impl Fn(&SpelledNote) -> Result<SpelledNote, TransposeError>,
+    ) -> Self {
+        let spelled_notes = self
+            .spelled_notes
+            .iter()
+            .map(move_note)
+            .collect::<Result<Vec<_>, _>>();
+
+        match spelled_notes {
+            Ok(spelled_notes) => transposed
+                .clone()
+                .with_spelled_notes(&spelled_notes)
+                .unwrap_or(transposed),
+            Err(_) => transposed,
+        }
+    }
+}
+
+// Note: semitone moves respell from the new root, interval moves carry each note's letter
+// along (see `ChordSpelling`)
+impl Transpose for ScaleSpelling {
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError> {
+        Ok(ScaleSpelling::new(&self.name.transpose(semitones)?))
+    }
+
+    fn transpose_up(&self, interval: &Interval) -> Result<Self, TransposeError> {
+        let transposed = self.transpose(interval.semitones() as i32)?;
+
+        Ok(self.carry_spellings(transposed, |spelled| spelled.transpose_up(interval)))
+    }
+
+    fn transpose_down(&self, interval: &Interval) -> Result<Self, TransposeError> {
+        let transposed = self.transpose(-(interval.semitones() as i32))?;
+
+        Ok(self.carry_spellings(transposed, |spelled| spelled.transpose_down(interval)))
+    }
+}
+
+impl Display for ScaleSpelling {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let degrees = self
+            .spelling
+            .iter()
+            .zip(self.spelled_notes.iter())
+            .map(|(degree, spelled)| format!("({}) {spelled}", degree.symbol()))
+            .collect::<Vec<_>>()
+            .join(" -- ");
+
+        writeln!(f, "Spelling for {}:\n{}", self.name, degrees)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JUST_MAJOR: &str = "9/8\n5/4\n4/3\n3/2\n5/3\n15/8\n2/1";
+
+    // semitones above the root of the major scale
+    const MAJOR: [usize; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+    fn spelled(scale_spelling: &ScaleSpelling) -> Vec<String> {
+        scale_spelling
+            .spelled_notes()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn steps_read_in_every_notation() {
+        for steps in ["WWHWWWH", "wwhwwwh", "2212221", "2-2-1-2-2-2-1", "WWHWWW"] {
+            let scale_steps = ScaleSteps::from_steps(steps).expect("should read the steps");
+
+            assert_eq!(scale_steps.semitones(), MAJOR, "{steps}");
+            assert_eq!(scale_steps.to_string(), "2212221", "{steps}");
+        }
+
+        let two_notes = ScaleSteps::from_steps("10-2").expect("should read two-digit steps");
+        assert_eq!(two_notes.semitones(), [0, 10]);
+        assert_eq!(two_notes.to_string(), "10-2");
+    }
+
+    #[test]
+    fn semitones_read_with_or_without_the_root() {
+        for semitones in [&MAJOR[..], &MAJOR[1..]] {
+            let scale_steps =
+                ScaleSteps::from_semitones(semitones).expect("should read the semitones");
+
+            assert_eq!(scale_steps.semitones(), MAJOR);
+        }
+
+        let scale_spelling = ScaleSpelling::from_semitones(&NoteName::A, &[0, 2, 3, 5, 7, 8, 11])
+            .expect("should read the semitones");
+        assert_eq!(
+            spelled(&scale_spelling),
+            ["A", "B", "C", "D", "E", "F", "G#"]
+        );
+    }
+
+    #[test]
+    fn scala_files_read_with_or_without_a_header() {
+        let texts = [
+            format!("! just.scl\n!\nJust major\n 7\n!\n{JUST_MAJOR}"),
+            // an empty description is still the description line
+            format!("!x.scl\n!\n\n 7\n{JUST_MAJOR}"),
+            format!("\n7\n{JUST_MAJOR}"),
+            // bare interval lists, in ratios or cents
+            JUST_MAJOR.replace('\n', " "),
+            "200.0, 400.0, 500.0, 700.0, 900.0, 1100.0, 1200.0".to_string(),
+            "9/8 5/4 4/3 3/2 5/3 15/8".to_string(),
+        ];
+
+        for text in texts {
+            let scale_steps = ScaleSteps::from_scala(&text).expect("should read the Scala text");
+
+            assert_eq!(scale_steps.semitones(), MAJOR, "{text}");
+        }
+
+        let scale_spelling =
+            ScaleSpelling::from_scala(&NoteName::D, JUST_MAJOR).expect("should read the ratios");
+        assert_eq!(
+            spelled(&scale_spelling),
+            ["D", "E", "F#", "G", "A", "B", "C#"]
+        );
+    }
+
+    #[test]
+    fn custom_scales_spell_by_letter() {
+        let scale_spelling =
+            ScaleSpelling::from_steps(&NoteName::C, "HAHWHAH").expect("should read the steps");
+
+        assert_eq!(
+            spelled(&scale_spelling),
+            ["C", "Db", "E", "F", "G", "Ab", "B"]
+        );
+        assert_eq!(
+            scale_spelling.name().to_string(),
+            ScaleName::try_from("C custom HAHWHAH")
+                .expect("should parse the custom scale name")
+                .to_string()
+        );
+    }
+
+    fn steps_error(result: Result<ScaleSteps, ScaleStepsError>) -> String {
+        result
+            .expect_err("should reject the scale")
+            .reason()
+            .to_string()
+    }
+
+    #[test]
+    fn bad_step_patterns_are_rejected() {
+        assert!(steps_error(ScaleSteps::from_steps("")).contains("no steps"));
+        assert!(steps_error(ScaleSteps::from_steps("WWXW")).contains("unknown step `X`"));
+        assert!(steps_error(ScaleSteps::from_steps("WWWWWWW")).contains("past the octave"));
+        assert!(steps_error(ScaleSteps::from_steps("12-")).contains("at least one note above"));
+
+        assert!(steps_error(ScaleSteps::from_semitones(&[])).contains("at least one note above"));
+        assert!(steps_error(ScaleSteps::from_semitones(&[0])).contains("at least one note above"));
+        assert!(steps_error(ScaleSteps::from_semitones(&[0, 4, 2])).contains("should rise"));
+        // the octave itself is left out of the semitones
+        assert!(steps_error(ScaleSteps::from_semitones(&[0, 12])).contains("should rise"));
+    }
+
+    #[test]
+    fn bad_scala_files_are_rejected() {
+        let scala = |text| steps_error(ScaleSteps::from_scala(text));
+
+        // only the closing octave
+        assert!(scala("2/1").contains("at least one note above the root"));
+        assert!(scala("1200.0").contains("at least one note above the root"));
+        // `7/4` sits a third of a semitone below `A#`
+        assert!(scala("9/8 7/4 2/1").contains("cents off the nearest semitone"));
+        assert!(scala("9/8 x 2/1").contains("`x` is not a pitch"));
+        assert!(scala("9/8 3/1").contains("outside of the octave"));
+        assert_eq!(
+            scala("scale\n3\n9/8\n5/4"),
+            "expected `3` pitches, found `2`"
+        );
+    }
+
+    #[test]
+    fn symmetric_scales_spell_plainly() {
+        let plain = [
+            ("D half-whole", "D Eb F F# G# A B C"),
+            ("B whole-half", "B C# D E F G G# A#"),
+            ("D augmented", "D F F# A A# C#"),
+            ("F#/Gb whole tone", "Gb Ab Bb C D E"),
+            ("F#/Gb half-whole", "Gb G A Bb C Db Eb E"),
+            ("F#/Gb bebop major", "Gb Ab Bb B Db D Eb F"),
+            ("D#/Eb blues", "Eb Gb Ab A Bb Db"),
+            // seven-note scales keep one letter each
+            ("A#/Bb altered", "A# B C# D E F# G#"),
+            ("C altered", "C Db Eb Fb Gb Ab Bb"),
+        ];
+
+        for (name, notes) in plain {
+            let scale_name = ScaleName::try_from(name).expect("should parse the scale name");
+            let spelled = scale_name
+                .spell(None)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+
+            assert_eq!(spelled.join(" "), notes, "{name}");
+        }
+
+        assert!(ScaleQuality::WholeTone.is_symmetric());
+        assert!(ScaleQuality::Augmented.is_symmetric());
+        assert!(!ScaleQuality::BebopDom.is_symmetric());
+        assert!(!ScaleQuality::Maj.is_symmetric());
+    }
+
+    #[test]
+    fn written_tonics_keep_their_letter() {
+        let tonic = |text: &str| SpelledNote::try_from(text).expect("should parse the tonic");
+        let notes = |scale: &str, text: &str| {
+            ScaleName::try_from(scale)
+                .expect("should parse the scale name")
+                .spelled_from(&tonic(text))
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        assert_eq!(notes("F#/Gb whole tone", "F#"), "F# G# A# C D E");
+        assert_eq!(notes("F#/Gb half-whole", "F#"), "F# G A A# C C# D# E");
+        assert_eq!(notes("A#/Bb altered", "Bb"), "Bb Cb Db Ebb Fb Gb Ab");
+        assert_eq!(notes("F#/Gb Maj", "F#"), "F# G# A# B C# D# E#");
+        // a tonic that isn't the scale's root falls back to `spell`
+        assert_eq!(notes("D augmented", "Eb"), "D F F# A A# C#");
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{NoteName, ScaleName, ScaleQuality, ScaleSpelling};
+use std::collections::HashMap;
+
+// === SCALE SPELLINGS === //
+
+// every root + quality spelled from its formula (see `ScaleSpelling::new`)
+pub fn generate_scale_spellings() -> HashMap<ScaleName, ScaleSpelling> {
+    (0..12)
+        .map(NoteName::from_pitch_class)
+        .flat_map(|root| {
+            ScaleQuality::all().into_iter().map(move |quality| {
+                let scale_name = ScaleName::new(&root, quality);
+                (scale_name, ScaleSpelling::new(&scale_name))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScaleSteps;
+
+    // hand-typed major, harmonic and melodic minor scales - kept to check the formula-built spellings against
+    static SCALE_DATA: &str = include_str!(".././major_minor_scales.txt");
+
+    #[test]
+    fn scale_spellings_match_table() {
+        let scale_spellings = generate_scale_spellings();
+
+        for (index, line) in SCALE_DATA.lines().enumerate() {
+            let Some((name, notes_str)) = line.split_once(" = ") else {
+                continue;
+            };
+            let typed_name = ScaleName::try_from(name.trim_matches('"')).unwrap();
+            let typed_notes = notes_str
+                .split(',')
+                .map(|note| NoteName::try_from(note.trim()).unwrap())
+                .collect::<Vec<_>>();
+
+            let computed_notes = scale_spellings
+                .get(&typed_name)
+                .unwrap_or_else(|| panic!("line {}: no spelling for `{}`", index + 1, name))
+                .spelling()
+                .iter()
+                .map(|degree| *degree.note())
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                computed_notes,
+                typed_notes,
+                "line {}: `{}`",
+                index + 1,
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn modes_rotate_their_parent() {
+        for quality in ScaleQuality::all() {
+            let Some((parent, number)) = quality.parent() else {
+                continue;
+            };
+            let parent_formula = parent.formula();
+            let (_, offset) = parent_formula[number - 1];
+
+            let rotated = (0..parent_formula.len())
+                .map(|step| {
+                    let (_, semitones) = parent_formula[(number - 1 + step) % parent_formula.len()];
+                    (step + 1, (semitones + 12 - offset) % 12)
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                quality.formula(),
+                rotated,
+                "`{}` is mode {} of `{}`",
+                quality,
+                number,
+                parent
+            );
+        }
+    }
+
+    #[test]
+    fn custom_steps_spell_like_built_in_scales() {
+        let scale_spellings = generate_scale_spellings();
+
+        for (name, built_in) in scale_spellings
+            .iter()
+            .filter(|(name, _)| name.get_quality().formula().len() == 7)
+        {
+            let semitones = name
+                .get_quality()
+                .formula()
+                .iter()
+                .map(|(_, semitones)| *semitones)
+                .collect::<Vec<_>>();
+            let steps = ScaleSteps::from_semitones(&semitones).unwrap();
+            let custom = ScaleSpelling::from_steps(&name.get_root(), &steps.to_string()).unwrap();
+
+            assert_eq!(steps.semitones(), semitones, "`{}`", name);
+            assert_eq!(
+                custom.spelled_notes(),
+                built_in.spelled_notes(),
+                "`{}` as `{}`",
+                name,
+                custom.name()
+            );
+        }
+    }
+}
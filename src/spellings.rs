@@ -0,0 +1,330 @@
+use crate::NoteName;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, thiserror::Error)]
+#[error("Error trying in conversion of spelled note `{0}` to `SpelledNote` (Note: expects a capital letter name + optional accidental ex: `C`, `Eb`, `F#`, `Bbb` or `G##`)")]
+pub struct SpelledNoteError(String);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Letter {
+    C,
+    D,
+    E,
+    F,
+    G,
+    A,
+    B,
+}
+
+impl Letter {
+    // position in the `C D E F G A B` letter cycle
+    pub fn index(&self) -> usize {
+        match self {
+            Letter::C => 0,
+            Letter::D => 1,
+            Letter::E => 2,
+            Letter::F => 3,
+            Letter::G => 4,
+            Letter::A => 5,
+            Letter::B => 6,
+        }
+    }
+
+    // Note: wraps values outside of `0..7` back into the letter cycle
+    pub fn from_index(index: usize) -> Self {
+        match index % 7 {
+            0 => Letter::C,
+            1 => Letter::D,
+            2 => Letter::E,
+            3 => Letter::F,
+            4 => Letter::G,
+            5 => Letter::A,
+            _ => Letter::B,
+        }
+    }
+
+    // letter `steps` positions above (ex: `C` + 2 = `E`, `A` + 2 = `C`)
+    pub fn offset(&self, steps: usize) -> Self {
+        Letter::from_index(self.index() + steps)
+    }
+
+    // semitones above `C` for the natural (unaltered) letter
+    pub fn natural_pitch_class(&self) -> usize {
+        match self {
+            Letter::C => 0,
+            Letter::D => 2,
+            Letter::E => 4,
+            Letter::F => 5,
+            Letter::G => 7,
+            Letter::A => 9,
+            Letter::B => 11,
+        }
+    }
+}
+
+impl Display for Letter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Letter::C => write!(f, "C"),
+            Letter::D => write!(f, "D"),
+            Letter::E => write!(f, "E"),
+            Letter::F => write!(f, "F"),
+            Letter::G => write!(f, "G"),
+            Letter::A => write!(f, "A"),
+            Letter::B => write!(f, "B"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Accidental {
+    DoubleFlat,
+    Flat,
+    Natural,
+    Sharp,
+    DoubleSharp,
+}
+
+impl Accidental {
+    pub fn semitones(&self) -> i32 {
+        match self {
+            Accidental::DoubleFlat => -2,
+            Accidental::Flat => -1,
+            Accidental::Natural => 0,
+            Accidental::Sharp => 1,
+            Accidental::DoubleSharp => 2,
+        }
+    }
+
+    pub fn from_semitones(semitones: i32) -> Option<Self> {
+        match semitones {
+            -2 => Some(Accidental::DoubleFlat),
+            -1 => Some(Accidental::Flat),
+            0 => Some(Accidental::Natural),
+            1 => Some(Accidental::Sharp),
+            2 => Some(Accidental::DoubleSharp),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Accidental {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Accidental::DoubleFlat => write!(f, "bb"),
+            Accidental::Flat => write!(f, "b"),
+            Accidental::Natural => write!(f, ""),
+            Accidental::Sharp => write!(f, "#"),
+            Accidental::DoubleSharp => write!(f, "##"),
+        }
+    }
+}
+
+// a letter-based note name (`C#` and `Db` are different `SpelledNote`s that share one `NoteName`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpelledNote {
+    letter: Letter,
+    accidental: Accidental,
+}
+
+impl SpelledNote {
+    pub fn new(letter: Letter, accidental: Accidental) -> Self {
+        Self { letter, accidental }
+    }
+
+    pub fn letter(&self) -> Letter {
+        self.letter
+    }
+
+    pub fn accidental(&self) -> Accidental {
+        self.accidental
+    }
+
+    pub fn note_name(&self) -> NoteName {
+        let pitch_class = self.letter.natural_pitch_class() as i32 + self.accidental.semitones();
+
+        NoteName::from_pitch_class(pitch_class.rem_euclid(12) as usize)
+    }
+
+    // spell `note` with the given `letter` - `None` if it would take more than a double accidental
+    pub fn from_letter(letter: Letter, note: &NoteName) -> Option<Self> {
+        let diff = (note.pitch_class() as i32 - letter.natural_pitch_class() as i32).rem_euclid(12);
+        // fold into `-6..6` so `B` -> `C` reads as one semitone up, not eleven down
+        let diff = if diff > 6 { diff - 12 } else { diff };
+
+        Accidental::from_semitones(diff).map(|accidental| SpelledNote::new(letter, accidental))
+    }
+
+    // letter-based spelling of the note `semitones` above, named as the given scale/chord `degree`
+    // (ex: `Eb` with degree 3 & 4 semitones -> `G`, `B` with degree 3 & 4 semitones -> `D#`)
+    pub fn above(&self, degree: usize, semitones: usize) -> Option<Self> {
+        let letter = self.letter.offset(degree.saturating_sub(1));
+        let note = NoteName::from_pitch_class(self.note_name().pitch_class() + semitones);
+
+        SpelledNote::from_letter(letter, &note)
+    }
+
+    // letter-based spelling of the note `semitones` below, `degree` letters down counting this one
+    // (ex: `G` with degree 3 & 4 semitones -> `Eb`, `C` with degree 1 & 1 semitone -> `Cb`)
+    pub fn below(&self, degree: usize, semitones: usize) -> Option<Self> {
+        let steps = degree.saturating_sub(1) % 7;
+        let letter = self.letter.offset(7 - steps);
+        let note = NoteName::from_pitch_class(self.note_name().pitch_class() + 12 - semitones % 12);
+
+        SpelledNote::from_letter(letter, &note)
+    }
+
+    // every spelling of `note` within a double accidental (ex: `D` -> `C##`, `D`, `Ebb`)
+    pub fn spellings(note: &NoteName) -> Vec<Self> {
+        [
+            Letter::C,
+            Letter::D,
+            Letter::E,
+            Letter::F,
+            Letter::G,
+            Letter::A,
+            Letter::B,
+        ]
+        .into_iter()
+        .filter_map(|letter| SpelledNote::from_letter(letter, note))
+        .collect()
+    }
+}
+
+impl Display for SpelledNote {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.letter, self.accidental)
+    }
+}
+
+impl From<SpelledNote> for NoteName {
+    fn from(value: SpelledNote) -> Self {
+        value.note_name()
+    }
+}
+
+// Note: default spelling when there is no other context (`C#`, `Eb`, `F#`, `Ab`, `Bb`)
+impl From<NoteName> for SpelledNote {
+    fn from(value: NoteName) -> Self {
+        match value {
+            NoteName::C => SpelledNote::new(Letter::C, Accidental::Natural),
+            NoteName::CSharpDFlat => SpelledNote::new(Letter::C, Accidental::Sharp),
+            NoteName::D => SpelledNote::new(Letter::D, Accidental::Natural),
+            NoteName::DSharpEFlat => SpelledNote::new(Letter::E, Accidental::Flat),
+            NoteName::E => SpelledNote::new(Letter::E, Accidental::Natural),
+            NoteName::F => SpelledNote::new(Letter::F, Accidental::Natural),
+            NoteName::FSharpGFlat => SpelledNote::new(Letter::F, Accidental::Sharp),
+            NoteName::G => SpelledNote::new(Letter::G, Accidental::Natural),
+            NoteName::GSharpAFlat => SpelledNote::new(Letter::A, Accidental::Flat),
+            NoteName::A => SpelledNote::new(Letter::A, Accidental::Natural),
+            NoteName::ASharpBFlat => SpelledNote::new(Letter::B, Accidental::Flat),
+            NoteName::B => SpelledNote::new(Letter::B, Accidental::Natural),
+        }
+    }
+}
+
+impl TryFrom<&str> for SpelledNote {
+    type Error = SpelledNoteError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut chars = value.chars();
+
+        let letter = match chars.next() {
+            Some('C') => Letter::C,
+            Some('D') => Letter::D,
+            Some('E') => Letter::E,
+            Some('F') => Letter::F,
+            Some('G') => Letter::G,
+            Some('A') => Letter::A,
+            Some('B') => Letter::B,
+            _ => return Err(SpelledNoteError(value.to_string())),
+        };
+
+        let accidental = match chars.as_str() {
+            "" => Accidental::Natural,
+            "#" | "♯" => Accidental::Sharp,
+            "##" | "♯♯" | "x" | "𝄪" => Accidental::DoubleSharp,
+            "b" | "♭" => Accidental::Flat,
+            "bb" | "♭♭" | "𝄫" => Accidental::DoubleFlat,
+            _ => return Err(SpelledNoteError(value.to_string())),
+        };
+
+        Ok(SpelledNote::new(letter, accidental))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(text: &str) -> SpelledNote {
+        SpelledNote::try_from(text).expect("should parse the spelled note")
+    }
+
+    #[test]
+    fn enharmonic_spellings_share_a_note_name() {
+        assert_ne!(note("C#"), note("Db"));
+        assert_eq!(note("C#").note_name(), NoteName::CSharpDFlat);
+        assert_eq!(note("Db").note_name(), NoteName::CSharpDFlat);
+
+        // double accidentals and letters past `B` / below `C` wrap around the octave
+        assert_eq!(note("B#").note_name(), NoteName::C);
+        assert_eq!(note("Cb").note_name(), NoteName::B);
+        assert_eq!(note("F##").note_name(), NoteName::G);
+        assert_eq!(note("Dbb").note_name(), NoteName::C);
+        assert_eq!(note("Bx").note_name(), NoteName::CSharpDFlat);
+        assert_eq!(note("C𝄫").note_name(), NoteName::ASharpBFlat);
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for text in ["C", "C#", "Db", "E#", "Fb", "G##", "Abb", "B"] {
+            assert_eq!(note(text).to_string(), text);
+        }
+
+        // other accidental symbols read back in their ASCII form
+        assert_eq!(note("E♭").to_string(), "Eb");
+        assert_eq!(note("F𝄪").to_string(), "F##");
+    }
+
+    #[test]
+    fn unreadable_notes_are_rejected() {
+        for text in ["", "H", "c", "C###", "Cbbb", "C#b"] {
+            assert!(SpelledNote::try_from(text).is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn letters_stop_at_double_accidentals() {
+        assert_eq!(
+            SpelledNote::from_letter(Letter::B, &NoteName::CSharpDFlat),
+            Some(note("B##"))
+        );
+        assert_eq!(
+            SpelledNote::from_letter(Letter::E, &NoteName::D),
+            Some(note("Ebb"))
+        );
+        assert_eq!(SpelledNote::from_letter(Letter::E, &NoteName::C), None);
+
+        let spellings = SpelledNote::spellings(&NoteName::D);
+        assert_eq!(spellings, [note("C##"), note("D"), note("Ebb")]);
+        // `G#/Ab` has no double-accidental spelling
+        assert_eq!(
+            SpelledNote::spellings(&NoteName::GSharpAFlat),
+            [note("G#"), note("Ab")]
+        );
+    }
+
+    #[test]
+    fn intervals_follow_their_letters() {
+        // a major third is always two letters up, whatever the accidental it takes
+        assert_eq!(note("Eb").above(3, 4), Some(note("G")));
+        assert_eq!(note("B").above(3, 4), Some(note("D#")));
+        assert_eq!(note("D#").above(3, 4), Some(note("F##")));
+        assert_eq!(note("Fb").above(3, 3), Some(note("Abb")));
+        assert_eq!(note("G").below(3, 4), Some(note("Eb")));
+        assert_eq!(note("C").below(1, 1), Some(note("Cb")));
+        // a triple sharp can't be written
+        assert_eq!(note("A##").above(3, 4), None);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{Interval, NoteName, NoteOct, SpelledNote};
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransposeError {
+    #[error("ERROR: transposing `{0}` by `{1}` semitones leaves the supported range (Note: octaves range from `-2` to `9`)")]
+    OutOfRange(String, i32),
+    #[error("ERROR: transposing `{0}` by `{1}` has no letter-based spelling (Note: spellings are limited to double sharps/flats)")]
+    Unspellable(SpelledNote, Interval),
+}
+
+// shared transposition for notes, chords, scales and voicings
+// (Note: pitch-class types - `NoteName`, `ChordName`, `ScaleName` - wrap around the octave,
+// octave-aware types - `NoteOct`, `ChordVoicing` - fail outside of `NoteOct::OCTAVES`)
+pub trait Transpose: Sized {
+    // move by `semitones` (negative values transpose down)
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError>;
+
+    fn transpose_up(&self, interval: &Interval) -> Result<Self, TransposeError> {
+        self.transpose(interval.semitones() as i32)
+    }
+
+    fn transpose_down(&self, interval: &Interval) -> Result<Self, TransposeError> {
+        self.transpose(-(interval.semitones() as i32))
+    }
+}
+
+impl Transpose for NoteName {
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError> {
+        let pitch_class = (self.pitch_class() as i32 + semitones).rem_euclid(12);
+
+        Ok(NoteName::from_pitch_class(pitch_class as usize))
+    }
+}
+
+impl Transpose for NoteOct {
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError> {
+        let note = usize::try_from(self.semitones() as i64 + semitones as i64)
+            .ok()
+            .and_then(NoteOct::from_semitone_index);
+
+        note.ok_or_else(|| TransposeError::OutOfRange(self.to_string(), semitones))
+    }
+}
+
+// letter-based - moving by an interval also moves the letter (ex: `Eb` up a `M3` is `G`, `D#`
+// up a `M3` is `F##`), semitone moves use the common interval name (ex: `6` -> `A4`)
+impl Transpose for SpelledNote {
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError> {
+        let interval = Interval::from_semitones(semitones.unsigned_abs() as usize);
+
+        if semitones < 0 {
+            self.transpose_down(&interval)
+        } else {
+            self.transpose_up(&interval)
+        }
+    }
+
+    fn transpose_up(&self, interval: &Interval) -> Result<Self, TransposeError> {
+        self.above(interval.number(), interval.semitones())
+            .ok_or(TransposeError::Unspellable(*self, *interval))
+    }
+
+    fn transpose_down(&self, interval: &Interval) -> Result<Self, TransposeError> {
+        self.below(interval.number(), interval.semitones())
+            .ok_or(TransposeError::Unspellable(*self, *interval))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ScaleName, ScaleQuality, ScaleSpelling};
+
+    fn up(note: &str, interval: &str) -> String {
+        let note = SpelledNote::try_from(note).unwrap();
+        let interval = Interval::try_from(interval).unwrap();
+
+        note.transpose_up(&interval).unwrap().to_string()
+    }
+
+    fn down(note: &str, interval: &str) -> String {
+        let note = SpelledNote::try_from(note).unwrap();
+        let interval = Interval::try_from(interval).unwrap();
+
+        note.transpose_down(&interval).unwrap().to_string()
+    }
+
+    #[test]
+    fn spelled_notes_move_their_letter() {
+        assert_eq!(up("Eb", "M3"), "G");
+        assert_eq!(up("D#", "M3"), "F##");
+        assert_eq!(down("Eb", "M3"), "Cb");
+        assert_eq!(down("B", "m9"), "A#");
+        assert_eq!(up("B", "m9"), "C");
+    }
+
+    #[test]
+    fn spelled_notes_move_by_unisons_and_octaves() {
+        assert_eq!(up("C", "P1"), "C");
+        assert_eq!(up("C", "A1"), "C#");
+        assert_eq!(down("C", "A1"), "Cb");
+        assert_eq!(up("F#", "A1"), "F##");
+        assert_eq!(down("F#", "A1"), "F");
+        assert_eq!(up("E", "d8"), "Eb");
+        assert_eq!(down("E", "d8"), "E#");
+        assert_eq!(down("C", "P8"), "C");
+        assert_eq!(up("D#", "P15"), "D#");
+
+        let a1 = Interval::try_from("A1").unwrap();
+        assert_eq!(NoteOct::C(4).transpose_down(&a1).unwrap(), NoteOct::B(3));
+        assert_eq!(NoteName::C.transpose_down(&a1).unwrap(), NoteName::B);
+    }
+
+    #[test]
+    fn moves_past_the_limits_fail() {
+        let f_double_sharp = SpelledNote::try_from("F##").unwrap();
+        let a1 = Interval::try_from("A1").unwrap();
+
+        assert!(matches!(
+            f_double_sharp.transpose_up(&a1),
+            Err(TransposeError::Unspellable(..))
+        ));
+        assert!(matches!(
+            NoteOct::B(9).transpose(1),
+            Err(TransposeError::OutOfRange(..))
+        ));
+        assert!(NoteOct::C(-2).transpose(-1).is_err());
+        assert_eq!(NoteOct::C(-2).transpose(12).unwrap(), NoteOct::C(-1));
+    }
+
+    #[test]
+    fn scale_spellings_move_down_by_unisons() {
+        let c_major = ScaleSpelling::new(&ScaleName::C(ScaleQuality::Maj));
+        let a1 = Interval::try_from("A1").unwrap();
+
+        let spelled = c_major
+            .transpose_down(&a1)
+            .unwrap()
+            .spelled_notes()
+            .iter()
+            .map(|note| note.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(spelled, ["Cb", "Db", "Eb", "Fb", "Gb", "Ab", "Bb"]);
+    }
+}
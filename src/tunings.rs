@@ -0,0 +1,228 @@
+use crate::{NoteName, NoteOct};
+use std::fmt::{Display, Formatter};
+
+// tuning systems for turning `NoteOct`s into frequencies
+// (Note: `A4` always sounds at the reference pitch - every other note is placed around it)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Tuning {
+    #[default]
+    EqualTemperament,
+    // 5-limit just intonation relative to a tonic
+    JustIntonation(NoteName),
+    // pure `3:2` fifths stacked from a tonic
+    Pythagorean(NoteName),
+    // fifths narrowed by a quarter syntonic comma (pure major thirds) from a tonic
+    QuarterCommaMeantone(NoteName),
+    // Werckmeister III well temperament (fixed to `C`)
+    Werckmeister,
+    // Vallotti well temperament (fixed to `C`)
+    Vallotti,
+}
+
+impl Tuning {
+    // cents above the tuning's tonic for each semitone step (`0..12`) above it
+    fn step_cents(&self) -> [f64; 12] {
+        match self {
+            Tuning::EqualTemperament => [
+                0.0, 100.0, 200.0, 300.0, 400.0, 500.0, 600.0, 700.0, 800.0, 900.0, 1000.0, 1100.0,
+            ],
+            Tuning::JustIntonation(_) => [
+                (1.0, 1.0),
+                (16.0, 15.0),
+                (9.0, 8.0),
+                (6.0, 5.0),
+                (5.0, 4.0),
+                (4.0, 3.0),
+                (45.0, 32.0),
+                (3.0, 2.0),
+                (8.0, 5.0),
+                (5.0, 3.0),
+                (16.0, 9.0),
+                (15.0, 8.0),
+            ]
+            .map(|(num, den)| ratio_cents(num / den)),
+            Tuning::Pythagorean(_) => [
+                (1.0, 1.0),
+                (256.0, 243.0),
+                (9.0, 8.0),
+                (32.0, 27.0),
+                (81.0, 64.0),
+                (4.0, 3.0),
+                (729.0, 512.0),
+                (3.0, 2.0),
+                (128.0, 81.0),
+                (27.0, 16.0),
+                (16.0, 9.0),
+                (243.0, 128.0),
+            ]
+            .map(|(num, den)| ratio_cents(num / den)),
+            Tuning::QuarterCommaMeantone(_) => {
+                // a quarter-comma fifth is `5^(1/4)` - each step sits on the tonic's chain of
+                // fifths, from three fifths below (`b3`) to eight above (`#5`)
+                let fifth = ratio_cents(5f64.powf(0.25));
+                [0, 7, 2, -3, 4, -1, 6, 1, 8, 3, -2, 5]
+                    .map(|fifths: i32| (fifths as f64 * fifth).rem_euclid(1200.0))
+            }
+            Tuning::Werckmeister => [
+                0.0, 90.225, 192.18, 294.135, 390.225, 498.045, 588.27, 696.09, 792.18, 888.27,
+                996.09, 1092.18,
+            ],
+            Tuning::Vallotti => [
+                0.0, 94.135, 196.09, 298.045, 392.18, 501.955, 592.18, 698.045, 796.09, 894.135,
+                1000.0, 1090.225,
+            ],
+        }
+    }
+
+    pub fn tonic(&self) -> NoteName {
+        match self {
+            Tuning::JustIntonation(tonic)
+            | Tuning::Pythagorean(tonic)
+            | Tuning::QuarterCommaMeantone(tonic) => *tonic,
+            Tuning::EqualTemperament | Tuning::Werckmeister | Tuning::Vallotti => NoteName::C,
+        }
+    }
+
+    // signed deviation (cents) of `note` from its equal tempered pitch
+    pub fn cents_offset(&self, note: &NoteName) -> f64 {
+        let step = (note.pitch_class() + 12 - self.tonic().pitch_class()) % 12;
+
+        self.step_cents()[step] - step as f64 * 100.0
+    }
+
+    pub fn frequency(&self, note: &NoteOct, reference_pitch: f64) -> f64 {
+        let offset = self.cents_offset(&note.note_name()) - self.cents_offset(&NoteName::A);
+
+        note.frequency(reference_pitch) * 2f64.powf(offset / 1200.0)
+    }
+}
+
+impl Display for Tuning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tuning::EqualTemperament => write!(f, "12-tone equal temperament"),
+            Tuning::JustIntonation(tonic) => write!(f, "5-limit just intonation ({tonic})"),
+            Tuning::Pythagorean(tonic) => write!(f, "Pythagorean ({tonic})"),
+            Tuning::QuarterCommaMeantone(tonic) => write!(f, "quarter-comma meantone ({tonic})"),
+            Tuning::Werckmeister => write!(f, "Werckmeister III"),
+            Tuning::Vallotti => write!(f, "Vallotti"),
+        }
+    }
+}
+
+fn ratio_cents(ratio: f64) -> f64 {
+    1200.0 * ratio.log2()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_cents(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "`{actual}` cents, expected `{expected}`"
+        );
+    }
+
+    // size in cents of the fifth above `note` (ex: `C` -> `C-G`)
+    fn fifth(tuning: &Tuning, note: NoteName) -> f64 {
+        let above = NoteName::from_pitch_class((note.pitch_class() + 7) % 12);
+
+        700.0 + tuning.cents_offset(&above) - tuning.cents_offset(&note)
+    }
+
+    #[test]
+    fn just_intonation_has_pure_thirds_and_fifths() {
+        let just = Tuning::JustIntonation(NoteName::C);
+        assert_cents(just.cents_offset(&NoteName::C), 0.0);
+        assert_cents(just.cents_offset(&NoteName::E), -13.686);
+        assert_cents(just.cents_offset(&NoteName::G), 1.955);
+        assert_cents(just.cents_offset(&NoteName::A), -15.641);
+        assert_cents(just.cents_offset(&NoteName::ASharpBFlat), -3.910);
+
+        // the tonic moves the whole table (ex: `F#` is the major third of `D`)
+        let just_d = Tuning::JustIntonation(NoteName::D);
+        assert_cents(just_d.cents_offset(&NoteName::FSharpGFlat), -13.686);
+        assert_cents(just_d.cents_offset(&NoteName::D), 0.0);
+
+        // `C4` a just major sixth under `A4 = 440`
+        assert!((just.frequency(&NoteOct::C(4), 440.0) - 264.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn pythagorean_stacks_pure_fifths() {
+        let pythagorean = Tuning::Pythagorean(NoteName::C);
+        for note in [
+            NoteName::C,
+            NoteName::D,
+            NoteName::E,
+            NoteName::F,
+            NoteName::A,
+        ] {
+            assert_cents(fifth(&pythagorean, note), 701.955);
+        }
+        assert_cents(pythagorean.cents_offset(&NoteName::E), 7.820);
+        assert_cents(pythagorean.cents_offset(&NoteName::FSharpGFlat), 11.730);
+        assert_cents(pythagorean.cents_offset(&NoteName::CSharpDFlat), -9.775);
+
+        // (Note: the wolf fifth sits between the chain's ends `F#` and `C#`)
+        assert_cents(fifth(&pythagorean, NoteName::FSharpGFlat), 678.495);
+    }
+
+    #[test]
+    fn quarter_comma_meantone_narrows_fifths_for_pure_thirds() {
+        let meantone = Tuning::QuarterCommaMeantone(NoteName::C);
+        assert_cents(meantone.cents_offset(&NoteName::D), -6.843);
+        assert_cents(meantone.cents_offset(&NoteName::E), -13.686);
+        assert_cents(meantone.cents_offset(&NoteName::G), -3.422);
+        assert_cents(meantone.cents_offset(&NoteName::DSharpEFlat), 10.265);
+        assert_cents(meantone.cents_offset(&NoteName::GSharpAFlat), -27.373);
+
+        assert_cents(fifth(&meantone, NoteName::C), 696.578);
+        assert_cents(fifth(&meantone, NoteName::GSharpAFlat), 737.637);
+    }
+
+    #[test]
+    fn well_temperaments_spread_the_comma() {
+        // Werckmeister III - `C-G`, `G-D`, `D-A` and `B-F#` each take a quarter of the
+        // Pythagorean comma, every other fifth is pure
+        let werckmeister = Tuning::Werckmeister;
+        for pitch_class in 0..12 {
+            let note = NoteName::from_pitch_class(pitch_class);
+            let tempered = [0, 7, 2, 11].contains(&pitch_class);
+            let expected = if tempered { 696.090 } else { 701.955 };
+            assert_cents(fifth(&werckmeister, note), expected);
+        }
+        assert_cents(werckmeister.cents_offset(&NoteName::E), -9.775);
+        assert_cents(werckmeister.cents_offset(&NoteName::A), -11.730);
+
+        // Vallotti - the six fifths from `F` to `B` take a sixth of the comma each
+        let vallotti = Tuning::Vallotti;
+        for pitch_class in 0..12 {
+            let note = NoteName::from_pitch_class(pitch_class);
+            let tempered = [5, 0, 7, 2, 9, 4].contains(&pitch_class);
+            let expected = if tempered { 698.045 } else { 701.955 };
+            assert_cents(fifth(&vallotti, note), expected);
+        }
+        assert_cents(vallotti.cents_offset(&NoteName::F), 1.955);
+        assert_cents(vallotti.cents_offset(&NoteName::B), -9.775);
+    }
+
+    #[test]
+    fn a4_sounds_at_the_reference_pitch_in_every_tuning() {
+        for tuning in [
+            Tuning::EqualTemperament,
+            Tuning::JustIntonation(NoteName::DSharpEFlat),
+            Tuning::Pythagorean(NoteName::G),
+            Tuning::QuarterCommaMeantone(NoteName::F),
+            Tuning::Werckmeister,
+            Tuning::Vallotti,
+        ] {
+            assert!(
+                (tuning.frequency(&NoteOct::A(4), 432.0) - 432.0).abs() < 1e-9,
+                "{tuning}"
+            );
+        }
+    }
+}
@@ -1,5 +1,8 @@
+use crate::transpose::{Transpose, TransposeError};
+use crate::{identify_chord, ChordCandidate};
 use crate::{
-    music_theory_baux, ChordName, ChordQuality, ChordSpelling, ChordTone, ChordToneDegree, NoteOct,
+    music_theory_baux, ChordName, ChordQuality, ChordSpelling, ChordTone, ChordToneDegree,
+    Interval, MusicTheoryBaux, NoteName, NoteOct, SlashChord, SpelledNote, SpellingForm, Tuning,
 };
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
@@ -27,8 +30,14 @@ pub struct VoiceLeadingConfig {
 pub enum ChordVoicingError {
     #[error("ERROR: failed to parse provided `chord_name` {0} (Note: should consist of `note letters` [`C`, `C#`, `Db, `C#/Db`] + symbols  [`maj`, `min`, `+`, `7sus4`]")]
     ParseChordName(String),
-    #[error("ERROR: invalid octave `{0}` provided for {0} (octave ranges are from `1` to `8`)")]
-    InvalidOct(usize, String),
+    #[error("ERROR: invalid octave `{0}` provided for {1} (octave ranges are from `-2` to `9`)")]
+    InvalidOct(i32, String),
+    #[error("ERROR: no octave for bass `{0}` below the chord's voices (Note: octave ranges are from `-2` to `9` - try raising the voice octaves)")]
+    BassOutOfRange(NoteName),
+    #[error("ERROR: no spelling found for chord `{0}` to voice lead to")]
+    MissingSpelling(String),
+    #[error("ERROR: no voice leading to `{0}` keeps the three voices on distinct notes")]
+    NoVoiceLeading(String),
 }
 
 #[allow(dead_code)]
@@ -39,19 +48,49 @@ pub enum ChordVoice {
     Third(NoteOct),
     Fourth(NoteOct),
     Fifth(NoteOct),
+    Sixth(NoteOct),
     Seventh(NoteOct),
+    Ninth(NoteOct),
+    Eleventh(NoteOct),
+    Thirteenth(NoteOct),
+    Bass(NoteOct),
 }
 
 #[allow(dead_code)]
 impl ChordVoice {
     fn from_parts(note: &NoteOct, chord_tone: &ChordTone) -> ChordVoice {
-        match chord_tone.get_tone_degree() {
+        ChordVoice::from_degree(&chord_tone.get_tone_degree(), note)
+    }
+
+    fn from_degree(degree: &ChordToneDegree, note: &NoteOct) -> ChordVoice {
+        match degree {
             ChordToneDegree::Root => ChordVoice::Root(*note),
             ChordToneDegree::Second => ChordVoice::Second(*note),
             ChordToneDegree::Third => ChordVoice::Third(*note),
             ChordToneDegree::Fourth => ChordVoice::Fourth(*note),
             ChordToneDegree::Fifth => ChordVoice::Fifth(*note),
+            ChordToneDegree::Sixth => ChordVoice::Sixth(*note),
             ChordToneDegree::Seventh => ChordVoice::Seventh(*note),
+            ChordToneDegree::Ninth => ChordVoice::Ninth(*note),
+            ChordToneDegree::Eleventh => ChordVoice::Eleventh(*note),
+            ChordToneDegree::Thirteenth => ChordVoice::Thirteenth(*note),
+            ChordToneDegree::Bass => ChordVoice::Bass(*note),
+        }
+    }
+
+    pub fn degree(&self) -> ChordToneDegree {
+        match self {
+            ChordVoice::Root(_) => ChordToneDegree::Root,
+            ChordVoice::Second(_) => ChordToneDegree::Second,
+            ChordVoice::Third(_) => ChordToneDegree::Third,
+            ChordVoice::Fourth(_) => ChordToneDegree::Fourth,
+            ChordVoice::Fifth(_) => ChordToneDegree::Fifth,
+            ChordVoice::Sixth(_) => ChordToneDegree::Sixth,
+            ChordVoice::Seventh(_) => ChordToneDegree::Seventh,
+            ChordVoice::Ninth(_) => ChordToneDegree::Ninth,
+            ChordVoice::Eleventh(_) => ChordToneDegree::Eleventh,
+            ChordVoice::Thirteenth(_) => ChordToneDegree::Thirteenth,
+            ChordVoice::Bass(_) => ChordToneDegree::Bass,
         }
     }
 
@@ -62,21 +101,28 @@ impl ChordVoice {
             ChordVoice::Third(note) => note,
             ChordVoice::Fourth(note) => note,
             ChordVoice::Fifth(note) => note,
+            ChordVoice::Sixth(note) => note,
             ChordVoice::Seventh(note) => note,
+            ChordVoice::Ninth(note) => note,
+            ChordVoice::Eleventh(note) => note,
+            ChordVoice::Thirteenth(note) => note,
+            ChordVoice::Bass(note) => note,
         }
     }
 }
 
+impl Transpose for ChordVoice {
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError> {
+        Ok(ChordVoice::from_degree(
+            &self.degree(),
+            &self.note_oct().transpose(semitones)?,
+        ))
+    }
+}
+
 impl Display for ChordVoice {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ChordVoice::Root(note) => write!(f, "Root: {note}"),
-            ChordVoice::Second(note) => write!(f, "Second: {note}"),
-            ChordVoice::Third(note) => write!(f, "Third: {note}"),
-            ChordVoice::Fourth(note) => write!(f, "Fourth: {note}"),
-            ChordVoice::Fifth(note) => write!(f, "Fifth: {note}"),
-            ChordVoice::Seventh(note) => write!(f, "Seventh: {note}"),
-        }
+        write!(f, "{}: {}", self.degree(), self.note_oct())
     }
 }
 
@@ -85,7 +131,13 @@ pub struct ChordVoicing {
     chord_name: ChordName,
     root: NoteOct,
     voices: (ChordVoice, ChordVoice, ChordVoice),
+    // slash chord bass - fixed below `voices` while voice leading
+    bass: Option<ChordVoice>,
+    spelled_notes: Vec<SpelledNote>,
     frequencies: Vec<f64>,
+    // what `frequencies` were computed with - kept through voice leading and transposing
+    tuning: Tuning,
+    reference_pitch: f64,
     note_weights: Vec<usize>,
     transitions: Option<HashMap<ChordVoice, ChordVoice>>,
 }
@@ -96,6 +148,14 @@ impl ChordVoicing {
         self.chord_name
     }
 
+    // chord + bass note (ex: `G7/B`) - root position when there is no bass voice
+    pub fn slash_chord(&self) -> SlashChord {
+        match self.bass {
+            Some(bass) => SlashChord::new(self.chord_name, &bass.note_oct().note_name()),
+            None => SlashChord::from(self.chord_name),
+        }
+    }
+
     pub fn quality(&self) -> ChordQuality {
         self.chord_name.get_quality()
     }
@@ -108,6 +168,14 @@ impl ChordVoicing {
         &self.frequencies
     }
 
+    pub fn tuning(&self) -> &Tuning {
+        &self.tuning
+    }
+
+    pub fn reference_pitch(&self) -> f64 {
+        self.reference_pitch
+    }
+
     pub fn root(&self) -> NoteOct {
         self.root
     }
@@ -116,13 +184,66 @@ impl ChordVoicing {
         &self.voices
     }
 
+    pub fn bass(&self) -> Option<&ChordVoice> {
+        self.bass.as_ref()
+    }
+
+    // `voices` followed by the bass voice, if any (the order of `frequencies`, `note_weights`
+    // and `spelled_notes`)
+    pub fn sounding_voices(&self) -> Vec<ChordVoice> {
+        [self.voices.0, self.voices.1, self.voices.2]
+            .into_iter()
+            .chain(self.bass)
+            .collect()
+    }
+
+    // letter-based spellings for each voice (same order as `sounding_voices`)
+    pub fn spelled_notes(&self) -> &Vec<SpelledNote> {
+        &self.spelled_notes
+    }
+
+    // same voicing with `frequencies` taken from `baux` (ex: one built for `A4 = 442`)
+    // (Note: voicings led or transposed from it keep `baux`'s tuning and reference pitch)
+    pub fn tuned_to(&self, baux: &MusicTheoryBaux) -> ChordVoicing {
+        ChordVoicing {
+            frequencies: self.frequencies_in(baux.tuning(), baux.reference_pitch()),
+            tuning: *baux.tuning(),
+            reference_pitch: baux.reference_pitch(),
+            ..self.clone()
+        }
+    }
+
+    // voice frequencies under another `tuning` (ex: comparing one voicing across temperaments)
+    pub fn frequencies_in(&self, tuning: &Tuning, reference_pitch: f64) -> Vec<f64> {
+        self.sounding_voices()
+            .iter()
+            .map(|voice| tuning.frequency(voice.note_oct(), reference_pitch))
+            .collect()
+    }
+
     pub fn get_transitions(&self) -> &Option<HashMap<ChordVoice, ChordVoice>> {
         &self.transitions
     }
 
     pub fn voice_lead(&self, new_chord: &ChordName) -> ChordVoicing {
-        let new_spelling = music_theory_baux.get_chord_spelling(new_chord).unwrap();
-        let new_voice_choices = self.get_new_voice_choices(new_spelling);
+        self.voice_lead_slash(&SlashChord::from(*new_chord))
+            .expect("should voice lead to a root position chord")
+    }
+
+    // voice leads the three upper voices as in `voice_lead` over a bass that stays below them
+    // (Note: the bass moves to its closest octave from the current bass, or just below the
+    // current lowest voice)
+    pub fn voice_lead_slash(
+        &self,
+        new_chord: &SlashChord,
+    ) -> Result<ChordVoicing, ChordVoicingError> {
+        let new_spelling = new_chord
+            .spelling(SpellingForm::Shell)
+            .ok_or_else(|| ChordVoicingError::MissingSpelling(new_chord.to_string()))?;
+        let upper_spelling = music_theory_baux
+            .get_chord_spelling(&new_chord.chord())
+            .ok_or_else(|| ChordVoicingError::MissingSpelling(new_chord.chord().to_string()))?;
+        let new_voice_choices = self.get_new_voice_choices(upper_spelling);
 
         let best_choices = self.calculate_best_choices(&new_voice_choices);
         let configurations = self.generate_leads(&best_choices);
@@ -134,21 +255,97 @@ impl ChordVoicing {
 
         let mut sorted_configs = valid_configs;
         sorted_configs.sort_by_key(|config| config.diff);
+        let closest = sorted_configs
+            .first()
+            .ok_or_else(|| ChordVoicingError::NoVoiceLeading(new_chord.to_string()))?;
+
+        if new_chord.is_root_position() {
+            return self.create_new_voicing(
+                &new_chord.chord(),
+                &new_spelling,
+                &closest.voices,
+                None,
+            );
+        }
 
-        self.create_new_voicing(new_chord, &sorted_configs[0].voices)
+        let lowest = lowest_note(&self.sounding_voices());
+        let bass = match self.bass {
+            Some(bass) => closest_octave(&new_chord.bass(), bass.note_oct()),
+            None => bass_below(&new_chord.bass(), &lowest)
+                .unwrap_or_else(|| closest_octave(&new_chord.bass(), &lowest)),
+        };
+        // the closest bass can cross above the upper voices - drop it below them, keeping the
+        // closest bass when the voices already sit in the lowest octave
+        let (voices, bass) = match sorted_configs
+            .iter()
+            .find(|config| lowest_note(&config.voices).semitones() > bass.semitones())
+        {
+            Some(config) => (&config.voices, bass),
+            None => {
+                let voices = &closest.voices;
+                let below = bass_below(&new_chord.bass(), &lowest_note(voices));
+                (voices, below.unwrap_or(bass))
+            }
+        };
+
+        self.create_new_voicing(
+            &new_chord.chord(),
+            &new_spelling,
+            voices,
+            Some(ChordVoice::Bass(bass)),
+        )
+    }
+
+    // intervals from the lowest sounding voice up to each of the others (lowest to highest)
+    pub fn intervals(&self) -> Vec<Interval> {
+        let mut notes = self
+            .sounding_voices()
+            .iter()
+            .map(|voice| *voice.note_oct())
+            .collect::<Vec<_>>();
+        notes.sort_by_key(NoteOct::semitones);
+
+        notes[1..].iter().map(|note| *note - notes[0]).collect()
+    }
+
+    // ranked chord readings of the sounding notes, lowest note as the bass (see `identify_chord`)
+    pub fn identify(&self) -> Vec<ChordCandidate> {
+        let mut notes = self
+            .sounding_voices()
+            .iter()
+            .map(|voice| *voice.note_oct())
+            .collect::<Vec<_>>();
+        notes.sort_by_key(NoteOct::semitones);
+
+        identify_chord(&notes.iter().map(NoteOct::note_name).collect::<Vec<_>>())
+    }
+
+    // `transposed` with this voicing's letters moved along (see `ChordSpelling`'s transposition)
+    fn carry_spellings(
+        &self,
+        transposed: Self,
+        move_note: impl Fn(&SpelledNote) -> Result<SpelledNote, TransposeError>,
+    ) -> Self {
+        match self.spelled_notes.iter().map(move_note).collect() {
+            Ok(spelled_notes) => Self {
+                spelled_notes,
+                ..transposed
+            },
+            Err(_) => transposed,
+        }
     }
 
     fn calculate_diff(&self, old_val: &ChordVoice, new_val: &ChordVoice) -> usize {
-        let old_weight = music_theory_baux
-            .get_note_weight(old_val.note_oct())
-            .unwrap();
-        let new_weight = music_theory_baux
-            .get_note_weight(new_val.note_oct())
-            .unwrap();
-        old_weight.abs_diff(*new_weight)
+        (*old_val.note_oct() - *new_val.note_oct()).semitones()
     }
 
-    fn create_new_voicing(&self, new_chord: &ChordName, new_voices: &[ChordVoice]) -> ChordVoicing {
+    fn create_new_voicing(
+        &self,
+        new_chord: &ChordName,
+        new_spelling: &ChordSpelling,
+        new_voices: &[ChordVoice],
+        bass: Option<ChordVoice>,
+    ) -> Result<ChordVoicing, ChordVoicingError> {
         println!("\n::new_chord: {}", new_chord);
         println!(
             "::new_voices: {} - {} - {}\n",
@@ -157,27 +354,39 @@ impl ChordVoicing {
 
         let mut note_weights = Vec::new();
         let mut frequencies = Vec::new();
+        let mut spelled_notes = Vec::new();
 
-        for voice in new_voices {
+        for voice in new_voices.iter().chain(bass.as_ref()) {
             let note_oct = voice.note_oct();
-            note_weights.push(*music_theory_baux.get_note_weight(note_oct).unwrap());
-            frequencies.push(*music_theory_baux.get_freq(note_oct).unwrap());
+            let weight = music_theory_baux.get_note_weight(note_oct).ok_or_else(|| {
+                ChordVoicingError::InvalidOct(note_oct.octave(), voice.to_string())
+            })?;
+            note_weights.push(*weight);
+            frequencies.push(self.tuning.frequency(note_oct, self.reference_pitch));
+            spelled_notes.push(spelled_voice(new_spelling, note_oct));
         }
 
-        let root = *new_voices
+        // extended chords can leave the root out of their three voices - it then sits in
+        // the lowest voice's octave
+        let lowest = lowest_note(new_voices);
+        let root = new_voices
             .iter()
-            .filter(|voice| voice.note_oct().note_name() == new_chord.get_root())
-            .collect::<Vec<_>>()[0]
-            .note_oct();
+            .map(|voice| *voice.note_oct())
+            .find(|note| note.note_name() == new_chord.get_root())
+            .unwrap_or_else(|| NoteOct::from_note(&new_chord.get_root(), lowest.octave()));
 
-        ChordVoicing {
+        Ok(ChordVoicing {
             chord_name: *new_chord,
             root,
             voices: (new_voices[0], new_voices[1], new_voices[2]),
+            bass,
+            spelled_notes,
             note_weights,
             transitions: None,
             frequencies,
-        }
+            tuning: self.tuning,
+            reference_pitch: self.reference_pitch,
+        })
     }
 
     pub fn get_new_voice_choices(&self, new_spelling: &ChordSpelling) -> Vec<ChordVoice> {
@@ -185,7 +394,7 @@ impl ChordVoicing {
             .spelling()
             .iter()
             .flat_map(|chord_tone| {
-                (1..=8).map(move |oct| {
+                NoteOct::OCTAVES.map(move |oct| {
                     let note_oct = NoteOct::from_note(chord_tone.note(), oct);
                     ChordVoice::from_parts(&note_oct, chord_tone)
                 })
@@ -320,6 +529,66 @@ impl ChordVoicing {
     }
 }
 
+// Note: transposed voicings keep the tuning and reference pitch of the original (see `tuned_to`)
+impl Transpose for ChordVoicing {
+    fn transpose(&self, semitones: i32) -> Result<Self, TransposeError> {
+        let chord_name = self.chord_name.transpose(semitones)?;
+        let voices = (
+            self.voices.0.transpose(semitones)?,
+            self.voices.1.transpose(semitones)?,
+            self.voices.2.transpose(semitones)?,
+        );
+
+        let bass = self
+            .bass
+            .map(|bass| bass.transpose(semitones))
+            .transpose()?;
+        let chord_spelling = match bass {
+            Some(bass) => SlashChord::new(chord_name, &bass.note_oct().note_name())
+                .spelling(SpellingForm::Shell),
+            None => music_theory_baux.get_chord_spelling(&chord_name).cloned(),
+        };
+
+        let mut note_weights = Vec::with_capacity(4);
+        let mut frequencies = Vec::with_capacity(4);
+        let mut spelled_notes = Vec::with_capacity(4);
+        for voice in [voices.0, voices.1, voices.2].iter().chain(bass.as_ref()) {
+            let note_oct = voice.note_oct();
+            note_weights.push(*music_theory_baux.get_note_weight(note_oct).unwrap());
+            frequencies.push(self.tuning.frequency(note_oct, self.reference_pitch));
+            spelled_notes.push(match &chord_spelling {
+                Some(chord_spelling) => spelled_voice(chord_spelling, note_oct),
+                None => SpelledNote::from(note_oct.note_name()),
+            });
+        }
+
+        Ok(ChordVoicing {
+            chord_name,
+            root: self.root.transpose(semitones)?,
+            voices,
+            bass,
+            spelled_notes,
+            frequencies,
+            tuning: self.tuning,
+            reference_pitch: self.reference_pitch,
+            note_weights,
+            transitions: None,
+        })
+    }
+
+    fn transpose_up(&self, interval: &Interval) -> Result<Self, TransposeError> {
+        let transposed = self.transpose(interval.semitones() as i32)?;
+
+        Ok(self.carry_spellings(transposed, |spelled| spelled.transpose_up(interval)))
+    }
+
+    fn transpose_down(&self, interval: &Interval) -> Result<Self, TransposeError> {
+        let transposed = self.transpose(-(interval.semitones() as i32))?;
+
+        Ok(self.carry_spellings(transposed, |spelled| spelled.transpose_down(interval)))
+    }
+}
+
 impl Default for ChordVoicing {
     fn default() -> Self {
         let voices = [NoteOct::C(3), NoteOct::E(3), NoteOct::G(3)];
@@ -339,35 +608,78 @@ impl Default for ChordVoicing {
                 ChordVoice::Third(third),
                 ChordVoice::Fifth(fifth),
             ),
+            bass: None,
+            spelled_notes: voices
+                .iter()
+                .map(|voice| SpelledNote::from(voice.note_name()))
+                .collect(),
             frequencies,
+            tuning: *music_theory_baux.tuning(),
+            reference_pitch: music_theory_baux.reference_pitch(),
             note_weights,
             transitions: None,
         }
     }
 }
 
+// lowest sounding note of `voices`
+fn lowest_note(voices: &[ChordVoice]) -> NoteOct {
+    voices
+        .iter()
+        .map(|voice| *voice.note_oct())
+        .min_by_key(NoteOct::semitones)
+        .expect("should be a voicing with at least one voice")
+}
+
+// `note` in the octave closest to `previous` (ex: `B` from `C3` -> `B2`)
+fn closest_octave(note: &NoteName, previous: &NoteOct) -> NoteOct {
+    NoteOct::OCTAVES
+        .map(|oct| NoteOct::from_note(note, oct))
+        .min_by_key(|candidate| candidate.semitones().abs_diff(previous.semitones()))
+        .expect("should be at least one octave")
+}
+
+// highest `note` sounding strictly below `lowest` (ex: `E` under `G3` -> `E3`)
+fn bass_below(note: &NoteName, lowest: &NoteOct) -> Option<NoteOct> {
+    NoteOct::OCTAVES
+        .map(|oct| NoteOct::from_note(note, oct))
+        .filter(|candidate| candidate.semitones() < lowest.semitones())
+        .max_by_key(NoteOct::semitones)
+}
+
+// chord spelling's letter-based name for a voice, falling back to the default spelling
+fn spelled_voice(spelling: &ChordSpelling, note_oct: &NoteOct) -> SpelledNote {
+    let note_name = note_oct.note_name();
+
+    spelling
+        .get_spelled_note(&note_name)
+        .copied()
+        .unwrap_or_else(|| SpelledNote::from(note_name))
+}
+
 //
 //
 //
 
 pub fn from_input(
     name: &str,
-    root_oct: Option<usize>,
-    voice_1_oct: Option<usize>,
-    voice_2_oct: Option<usize>,
+    root_oct: Option<i32>,
+    voice_1_oct: Option<i32>,
+    voice_2_oct: Option<i32>,
 ) -> Result<ChordVoicing, ChordVoicingError> {
-    let chord_name = ChordName::try_from(name);
+    let slash_chord = SlashChord::try_from(name);
 
-    if chord_name.is_ok() {
-        let chord_name = chord_name.unwrap();
-        let chord_notes = music_theory_baux
-            .get_chord_spelling(&chord_name)
-            .expect("should be a valid name access for `spelling` in `from_input`")
-            .spelling();
+    if let Ok(slash_chord) = slash_chord {
+        let chord_name = slash_chord.chord();
+        let chord_spelling = slash_chord
+            .spelling(SpellingForm::Shell)
+            .expect("should be a valid name access for `spelling` in `from_input`");
+        let chord_notes = chord_spelling.spelling();
+        let chord_spelling = &chord_spelling;
 
         let root_oct = match root_oct {
             Some(root_oct) => {
-                if !(1..=8).contains(&root_oct) {
+                if !NoteOct::OCTAVES.contains(&root_oct) {
                     return Err(ChordVoicingError::InvalidOct(root_oct, "root".to_string()));
                 }
                 root_oct
@@ -386,7 +698,7 @@ pub fn from_input(
 
         let voice_1_oct = match voice_1_oct {
             Some(voice_oct) => {
-                if !(1..=8).contains(&voice_oct) {
+                if !NoteOct::OCTAVES.contains(&voice_oct) {
                     return Err(ChordVoicingError::InvalidOct(
                         voice_oct,
                         "voice 1".to_string(),
@@ -408,7 +720,7 @@ pub fn from_input(
 
         let voice_2_oct = match voice_2_oct {
             Some(voice_oct) => {
-                if !(1..=8).contains(&voice_oct) {
+                if !NoteOct::OCTAVES.contains(&voice_oct) {
                     return Err(ChordVoicingError::InvalidOct(
                         voice_oct,
                         "voice 2".to_string(),
@@ -428,15 +740,101 @@ pub fn from_input(
             .expect("should be a valid 'second voice' `weight` lookup");
         let voice_2 = ChordVoice::from_parts(&note_oct_2, &chord_notes[2]);
 
+        let mut frequencies = vec![root_freq, voice_1_freq, voice_2_freq];
+        let mut note_weights = vec![root_weight, voice_1_weight, voice_2_weight];
+        let mut spelled_notes = vec![
+            spelled_voice(chord_spelling, &root_note_oct),
+            spelled_voice(chord_spelling, &note_oct_1),
+            spelled_voice(chord_spelling, &note_oct_2),
+        ];
+
+        // slash chords add their bass as a fourth voice below the other three
+        let bass = match chord_spelling.bass() {
+            Some(bass) => {
+                let lowest = lowest_note(&[root_voice, voice_1, voice_2]);
+                let bass_note_oct =
+                    bass_below(bass, &lowest).ok_or(ChordVoicingError::BassOutOfRange(*bass))?;
+
+                frequencies.push(*music_theory_baux.get_freq(&bass_note_oct).unwrap());
+                note_weights.push(*music_theory_baux.get_note_weight(&bass_note_oct).unwrap());
+                spelled_notes.push(spelled_voice(chord_spelling, &bass_note_oct));
+                Some(ChordVoice::Bass(bass_note_oct))
+            }
+            None => None,
+        };
+
         Ok(ChordVoicing {
             chord_name,
             root: root_note_oct,
             transitions: None,
-            frequencies: vec![root_freq, voice_1_freq, voice_2_freq],
+            frequencies,
+            tuning: *music_theory_baux.tuning(),
+            reference_pitch: music_theory_baux.reference_pitch(),
             voices: (root_voice, voice_1, voice_2),
-            note_weights: vec![root_weight, voice_1_weight, voice_2_weight],
+            bass,
+            spelled_notes,
+            note_weights,
         })
     } else {
         Err(ChordVoicingError::ParseChordName(name.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slash_chords_keep_their_bass() -> Result<(), ChordVoicingError> {
+        let f_over_c = SlashChord::try_from("F/C").unwrap();
+
+        let led = from_input("C", Some(4), Some(4), Some(4))?.voice_lead_slash(&f_over_c)?;
+        let bass = led.bass().expect("should voice the slash bass");
+        assert_eq!(bass.note_oct().note_name(), NoteName::C);
+        let (root, voice_1, voice_2) = *led.voices();
+        let lowest = lowest_note(&[root, voice_1, voice_2]);
+        assert!(lowest.semitones() > bass.note_oct().semitones());
+
+        // no room below the lowest octave - the bass still sounds
+        let led = from_input("C", Some(-2), Some(-2), Some(-2))?.voice_lead_slash(&f_over_c)?;
+        let bass = led.bass().expect("should voice the slash bass");
+        assert_eq!(bass.note_oct().note_name(), NoteName::C);
+        assert_eq!(led.slash_chord(), f_over_c);
+
+        // and the highest octave leads back down to a root position chord
+        let led = from_input("G/B", Some(9), Some(9), Some(9))?
+            .voice_lead_slash(&SlashChord::try_from("C").unwrap())?;
+        assert_eq!(led.bass(), None);
+        assert!(led
+            .sounding_voices()
+            .iter()
+            .all(|voice| voice.note_oct().is_supported()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn tuned_voicings_keep_their_reference_pitch() {
+        let baux = MusicTheoryBaux::with_reference_pitch(442.0).unwrap();
+        let voicing = from_input("C", Some(4), Some(4), Some(4))
+            .unwrap()
+            .tuned_to(&baux);
+        assert_eq!(voicing.reference_pitch(), 442.0);
+
+        let led = voicing.voice_lead(&ChordName::A(ChordQuality::Min));
+        let transposed = led.transpose(2).unwrap();
+        for voicing in [&voicing, &led, &transposed] {
+            for (voice, freq) in voicing.sounding_voices().iter().zip(voicing.frequencies()) {
+                assert_eq!(freq, baux.get_freq(voice.note_oct()).unwrap());
+            }
+        }
+
+        // `A4` sounds at the reference pitch
+        let a4 = led
+            .sounding_voices()
+            .iter()
+            .position(|voice| *voice.note_oct() == NoteOct::A(4))
+            .expect("should lead `C4 E4 G4` to an `A4`");
+        assert_eq!(led.frequencies()[a4], 442.0);
+    }
+}